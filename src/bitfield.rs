@@ -0,0 +1,162 @@
+use crate::error::{ConnectionError, Result};
+use redis::aio::ConnectionManager;
+use redis::{Cmd, ToRedisArgs};
+
+/// BITFIELD 子字段的整数类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitFieldType {
+    signed: bool,
+    bits: u8,
+}
+
+impl BitFieldType {
+    /// 有符号整数，位宽为 1 ~ 64
+    pub fn signed(bits: u8) -> Self {
+        Self { signed: true, bits }
+    }
+
+    /// 无符号整数，位宽为 1 ~ 63
+    pub fn unsigned(bits: u8) -> Self {
+        Self {
+            signed: false,
+            bits,
+        }
+    }
+
+    /// 校验位宽并转换为命令参数，例如 `i8`、`u16`
+    fn to_arg(self) -> Result<String> {
+        let max = if self.signed { 64 } else { 63 };
+        if self.bits == 0 || self.bits > max {
+            return Err(ConnectionError::Configuration(format!(
+                "Invalid BITFIELD width {} (expected 1..={max})",
+                self.bits
+            )));
+        }
+        Ok(format!(
+            "{}{}",
+            if self.signed { 'i' } else { 'u' },
+            self.bits
+        ))
+    }
+}
+
+/// BITFIELD 写入及自增操作的溢出处理方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Overflow {
+    /// 回绕（默认）
+    #[default]
+    Wrap,
+    /// 饱和到类型的最小值或最大值
+    Sat,
+    /// 不执行该操作并返回 nil
+    Fail,
+}
+
+/// BITFIELD 命令构建器 - 在一次调用中对同一个字符串执行多个子字段操作
+///
+/// 通过 [`RedisUtils::bitfield`](crate::RedisUtils::bitfield) 创建，链式添加操作后调用
+/// [`BitField::execute`] 执行，结果与操作的添加顺序一一对应。
+pub struct BitField<'a> {
+    conn: &'a mut ConnectionManager,
+    cmd: Cmd,
+    error: Option<ConnectionError>,
+}
+
+impl<'a> BitField<'a> {
+    /// 创建 BITFIELD 命令构建器
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - Redis 连接管理器
+    /// * `key` - 字符串键名
+    ///
+    /// # Returns
+    ///
+    /// 返回 BitField 实例
+    pub fn new<K: ToRedisArgs>(conn: &'a mut ConnectionManager, key: K) -> Self {
+        let mut cmd = redis::cmd("BITFIELD");
+        cmd.arg(key);
+        Self {
+            conn,
+            cmd,
+            error: None,
+        }
+    }
+
+    /// 追加带类型的子命令，类型非法时记录错误并在执行时返回
+    fn push(mut self, op: &str, field: BitFieldType, offset: u64, value: Option<i64>) -> Self {
+        match field.to_arg() {
+            Ok(field) => {
+                self.cmd.arg(op).arg(field).arg(offset);
+                if let Some(value) = value {
+                    self.cmd.arg(value);
+                }
+            }
+            Err(e) => {
+                self.error.get_or_insert(e);
+            }
+        }
+        self
+    }
+
+    /// 读取子字段（GET）
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - 子字段类型
+    /// * `offset` - 位偏移量
+    pub fn get(self, field: BitFieldType, offset: u64) -> Self {
+        self.push("GET", field, offset, None)
+    }
+
+    /// 写入子字段并返回旧值（SET）
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - 子字段类型
+    /// * `offset` - 位偏移量
+    /// * `value` - 新值
+    pub fn set(self, field: BitFieldType, offset: u64, value: i64) -> Self {
+        self.push("SET", field, offset, Some(value))
+    }
+
+    /// 对子字段自增并返回新值（INCRBY）
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - 子字段类型
+    /// * `offset` - 位偏移量
+    /// * `delta` - 增量，可以为负数
+    pub fn incrby(self, field: BitFieldType, offset: u64, delta: i64) -> Self {
+        self.push("INCRBY", field, offset, Some(delta))
+    }
+
+    /// 设置之后的 SET 和 INCRBY 操作的溢出处理方式（OVERFLOW）
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - 溢出处理方式
+    pub fn overflow(mut self, mode: Overflow) -> Self {
+        let mode = match mode {
+            Overflow::Wrap => "WRAP",
+            Overflow::Sat => "SAT",
+            Overflow::Fail => "FAIL",
+        };
+        self.cmd.arg("OVERFLOW").arg(mode);
+        self
+    }
+
+    /// 执行 BITFIELD 命令
+    ///
+    /// # Returns
+    ///
+    /// 返回每个 GET/SET/INCRBY 操作的结果，溢出模式为 FAIL 且发生溢出时对应结果为 None
+    pub async fn execute(self) -> Result<Vec<Option<i64>>> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+
+        let result: Vec<Option<i64>> = self.cmd.query_async(self.conn).await?;
+        Ok(result)
+    }
+}
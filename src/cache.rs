@@ -0,0 +1,139 @@
+use crate::error::{ConnectionError, Result};
+use crate::utils::RedisUtils;
+use redis::aio::ConnectionManager;
+use redis::{AsyncCommands, ToRedisArgs};
+use serde::Serialize;
+use std::time::Duration;
+
+/// 带默认过期时间的缓存写入工具 - 保证写入的键不会因遗漏过期时间而永久存在
+///
+/// [`CacheUtils::set`] 与 [`CacheUtils::set_struct`] 总是使用默认过期时间；
+/// 需要单独指定时使用 `*_with_ttl` 版本，传入 None 表示有意写入永久键。
+#[derive(Debug, Clone, Copy)]
+pub struct CacheUtils {
+    default_ttl: Duration,
+}
+
+impl CacheUtils {
+    /// 创建缓存写入工具
+    ///
+    /// # Arguments
+    ///
+    /// * `default_ttl` - 默认过期时间，不能小于 1 毫秒
+    ///
+    /// # Returns
+    ///
+    /// 返回 CacheUtils 实例，过期时间不合法时返回配置错误
+    pub fn new(default_ttl: Duration) -> Result<Self> {
+        RedisUtils::set_expiry(default_ttl)?;
+        Ok(Self { default_ttl })
+    }
+
+    /// 获取默认过期时间
+    pub fn default_ttl(&self) -> Duration {
+        self.default_ttl
+    }
+
+    /// 使用默认过期时间设置字符串值
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 键名
+    /// * `value` - 值
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn set<K, V>(&self, conn: &mut ConnectionManager, key: K, value: V) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        self.set_with_ttl(conn, key, value, Some(self.default_ttl))
+            .await
+    }
+
+    /// 使用指定的过期时间设置字符串值
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 键名
+    /// * `value` - 值
+    /// * `ttl` - 过期时间，为 None 时写入永久键
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn set_with_ttl<K, V>(
+        &self,
+        conn: &mut ConnectionManager,
+        key: K,
+        value: V,
+        ttl: Option<Duration>,
+    ) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        match ttl {
+            Some(ttl) => {
+                let options =
+                    redis::SetOptions::default().with_expiration(RedisUtils::set_expiry(ttl)?);
+                let _: () = conn.set_options(key, value, options).await?;
+                Ok(())
+            }
+            None => RedisUtils::set(conn, key, value).await,
+        }
+    }
+
+    /// 使用默认过期时间以 JSON 格式设置结构体对象
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 键名
+    /// * `value` - 要存储的结构体对象
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn set_struct<K, T>(
+        &self,
+        conn: &mut ConnectionManager,
+        key: K,
+        value: &T,
+    ) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        T: Serialize,
+    {
+        self.set_struct_with_ttl(conn, key, value, Some(self.default_ttl))
+            .await
+    }
+
+    /// 使用指定的过期时间以 JSON 格式设置结构体对象
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 键名
+    /// * `value` - 要存储的结构体对象
+    /// * `ttl` - 过期时间，为 None 时写入永久键
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn set_struct_with_ttl<K, T>(
+        &self,
+        conn: &mut ConnectionManager,
+        key: K,
+        value: &T,
+        ttl: Option<Duration>,
+    ) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        T: Serialize,
+    {
+        let json_str = serde_json::to_string(value)
+            .map_err(|e| ConnectionError::Serialization(e.to_string()))?;
+        self.set_with_ttl(conn, key, json_str, ttl).await
+    }
+}
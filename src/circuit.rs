@@ -0,0 +1,204 @@
+use crate::error::{ConnectionError, Result};
+use crate::interceptor::Interceptor;
+use redis::aio::ConnectionManager;
+use redis::{Cmd, FromRedisValue};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// 熔断器配置
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    /// 连续多少次连接错误后打开熔断器
+    pub failure_threshold: u32,
+    /// 熔断器打开后多久进入半开状态，放行一次探测请求
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+/// 熔断器状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// 正常放行所有请求
+    Closed,
+    /// 所有请求立即失败
+    Open,
+    /// 冷却结束，只放行一次探测请求
+    HalfOpen,
+}
+
+#[derive(Debug)]
+enum BreakerState {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    HalfOpen,
+}
+
+/// 熔断器 - Redis 不可用时快速失败，避免每个请求都等到超时
+///
+/// 连续 `failure_threshold` 次连接错误（参见 [`ConnectionError::is_connection_error`]）后熔断器打开，
+/// 冷却期内的请求直接返回 `ConnectionError::Network("circuit open")`。冷却结束后进入半开状态，
+/// 只放行一次探测请求：成功则关闭熔断器，失败则重新打开。WRONGTYPE 等服务端错误不计入失败，
+/// 请求在完成前被取消（例如被外层超时丢弃）则按连接错误计入。
+///
+/// 熔断器同时实现了 [`Interceptor`]，可以注册到 [`crate::InterceptedConnection`] 中，
+/// 克隆之间共享同一份状态。
+#[derive(Clone)]
+pub struct CircuitBreaker {
+    conn: ConnectionManager,
+    config: CircuitBreakerConfig,
+    state: Arc<Mutex<BreakerState>>,
+}
+
+impl CircuitBreaker {
+    /// 创建熔断器
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - Redis 连接管理器
+    /// * `config` - 熔断器配置
+    ///
+    /// # Returns
+    ///
+    /// 返回处于关闭状态的 CircuitBreaker 实例
+    pub fn new(conn: ConnectionManager, config: CircuitBreakerConfig) -> Self {
+        Self {
+            conn,
+            config,
+            state: Arc::new(Mutex::new(BreakerState::Closed { failures: 0 })),
+        }
+    }
+
+    /// 获取当前状态，冷却已结束的打开状态报告为半开
+    pub fn state(&self) -> CircuitState {
+        match *self.state.lock().unwrap_or_else(|e| e.into_inner()) {
+            BreakerState::Closed { .. } => CircuitState::Closed,
+            BreakerState::Open { until } if Instant::now() < until => CircuitState::Open,
+            BreakerState::Open { .. } | BreakerState::HalfOpen => CircuitState::HalfOpen,
+        }
+    }
+
+    /// 获取内部连接管理器的克隆，通过它执行的命令不受熔断器保护
+    pub fn connection_manager(&self) -> ConnectionManager {
+        self.conn.clone()
+    }
+
+    /// 在熔断器保护下执行任意命令
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - 要执行的命令
+    ///
+    /// # Returns
+    ///
+    /// 返回解析后的回复，熔断器打开时返回 `ConnectionError::Network`
+    pub async fn query<T: FromRedisValue>(&self, cmd: &Cmd) -> Result<T> {
+        self.execute(|mut conn| async move {
+            let result: T = cmd.query_async(&mut conn).await?;
+            Ok(result)
+        })
+        .await
+    }
+
+    /// 在熔断器保护下执行任意操作，可用于包装 [`crate::RedisUtils`] 的方法
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - 接收连接管理器克隆的操作
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果，熔断器打开时返回 `ConnectionError::Network`
+    pub async fn execute<T, F, Fut>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(ConnectionManager) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.acquire()?;
+        let mut attempt = Attempt {
+            breaker: self,
+            done: false,
+        };
+        let result = f(self.conn.clone()).await;
+        attempt.done = true;
+        self.record(result.as_ref().map(|_| ()));
+        result
+    }
+
+    /// 判断请求是否可以放行，半开状态下只放行一次探测
+    fn acquire(&self) -> Result<()> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        match *state {
+            BreakerState::Closed { .. } => Ok(()),
+            BreakerState::Open { until } if Instant::now() >= until => {
+                *state = BreakerState::HalfOpen;
+                Ok(())
+            }
+            BreakerState::Open { .. } | BreakerState::HalfOpen => {
+                Err(ConnectionError::Network("circuit open".to_string()))
+            }
+        }
+    }
+
+    /// 根据请求结果更新状态
+    fn record(&self, result: std::result::Result<(), &ConnectionError>) {
+        let failed = result.is_err_and(|e| e.is_connection_error());
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let open = BreakerState::Open {
+            until: Instant::now() + self.config.cooldown,
+        };
+
+        *state = match (&*state, failed) {
+            (_, false) => BreakerState::Closed { failures: 0 },
+            (BreakerState::Closed { failures }, true) => {
+                let failures = failures + 1;
+                if failures >= self.config.failure_threshold {
+                    log::debug!("Circuit breaker opened after {failures} consecutive failures");
+                    open
+                } else {
+                    BreakerState::Closed { failures }
+                }
+            }
+            (BreakerState::HalfOpen, true) => {
+                log::debug!("Circuit breaker probe failed, reopening");
+                open
+            }
+            // 打开期间放行前已发出的请求失败，不延长冷却时间
+            (BreakerState::Open { until }, true) => BreakerState::Open { until: *until },
+        };
+    }
+}
+
+/// 一次放行的请求，请求未完成就被丢弃（例如外层超时取消）时按失败记录，
+/// 避免半开状态的探测请求被取消后熔断器永远停留在半开状态
+struct Attempt<'a> {
+    breaker: &'a CircuitBreaker,
+    done: bool,
+}
+
+impl Drop for Attempt<'_> {
+    fn drop(&mut self) {
+        if !self.done {
+            log::debug!("Circuit breaker request cancelled, recording as failure");
+            self.breaker.record(Err(&ConnectionError::Timeout));
+        }
+    }
+}
+
+impl Interceptor for CircuitBreaker {
+    fn before(&self, _command: &str) -> Result<()> {
+        self.acquire()
+    }
+
+    fn after(&self, _command: &str, result: std::result::Result<(), &ConnectionError>) {
+        self.record(result);
+    }
+}
@@ -1,4 +1,10 @@
 use crate::error::{ConnectionError, Result};
+use crate::proxy::{self, ProxyConfig, Target};
+use redis::AsyncConnectionConfig;
+use redis::aio::MultiplexedConnection;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 pub struct RedisConfig {
     /// Redis 主机地址
@@ -9,6 +15,29 @@ pub struct RedisConfig {
     pub password: String,
     /// Redis 数据库
     pub db: u8,
+    /// 预解析的 Redis 地址，设置后忽略 host 和 port，不再经过 DNS 解析
+    pub addr: Option<SocketAddr>,
+    /// 连接后是否发送 READONLY，用于在集群副本节点上执行读操作
+    pub readonly: bool,
+    /// TCP keepalive 探测的空闲时间，为 None 时使用系统默认设置（通常不开启）
+    pub tcp_keepalive: Option<Duration>,
+    /// 是否开启 TCP_NODELAY
+    pub tcp_nodelay: bool,
+    /// 是否使用 TLS 连接（需要启用 `tls` 特性）
+    pub tls: bool,
+    /// 用于校验服务器证书的 CA 证书（PEM）路径，为 None 时读取环境变量
+    /// `REDIS_TLS_CA_CERT`，都未设置时使用系统信任库
+    pub ca_cert_path: Option<PathBuf>,
+    /// 双向 TLS 的客户端证书（PEM）路径，需要与 `client_key_path` 同时设置
+    pub client_cert_path: Option<PathBuf>,
+    /// 双向 TLS 的客户端私钥（PEM）路径，需要与 `client_cert_path` 同时设置
+    pub client_key_path: Option<PathBuf>,
+    /// 创建客户端时是否以 debug 级别记录连接地址（密码始终会被遮盖）
+    pub log_connection_info: bool,
+    /// 经由 SOCKS5 代理连接，设置后只能通过 [`RedisClient::proxied_connection`] 建立连接
+    pub proxy: Option<ProxyConfig>,
+    /// 经由代理建立连接（连接代理、SOCKS5 握手及 Redis 握手）的超时时间
+    pub connection_timeout: Duration,
 }
 
 impl Default for RedisConfig {
@@ -18,10 +47,24 @@ impl Default for RedisConfig {
             port: 6379,
             password: "".to_string(),
             db: 0,
+            addr: None,
+            readonly: false,
+            tcp_keepalive: None,
+            tcp_nodelay: false,
+            tls: false,
+            ca_cert_path: None,
+            client_cert_path: None,
+            client_key_path: None,
+            log_connection_info: true,
+            proxy: None,
+            connection_timeout: Duration::from_secs(30),
         }
     }
 }
 
+/// 指定自定义 CA 证书路径的环境变量名
+pub const TLS_CA_CERT_ENV: &str = "REDIS_TLS_CA_CERT";
+
 /// Redis 客户端封装
 pub struct RedisClient;
 
@@ -36,40 +79,229 @@ impl RedisClient {
     ///
     /// 返回 Redis Client 实例或错误
     pub fn create(config: RedisConfig) -> Result<redis::Client> {
-        let redis_url = Self::build_redis_url(&config)?;
+        Self::open(&config)
+    }
 
-        log::debug!("Redis URL: {redis_url}",);
+    /// 根据配置打开 Redis 客户端，启用 TLS 时加载配置的证书
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Redis 配置信息
+    ///
+    /// # Returns
+    ///
+    /// 返回 Redis Client 实例，证书文件缺失或无法解析时返回配置错误；
+    /// 配置了代理时返回配置错误，因为客户端只能直接连接，请改用 [`RedisClient::proxied_connection`]
+    pub fn open(config: &RedisConfig) -> Result<redis::Client> {
+        if config.proxy.is_some() {
+            return Err(ConnectionError::Configuration(
+                "A proxy is configured but redis::Client connects directly, use RedisClient::proxied_connection"
+                    .to_string(),
+            ));
+        }
+        Self::open_direct(config)
+    }
 
-        let client = redis::Client::open(redis_url)?;
+    /// 打开客户端而不检查代理配置，代理连接只借用其中的认证和选库信息
+    fn open_direct(config: &RedisConfig) -> Result<redis::Client> {
+        let redis_url = Self::build_redis_url(config)?;
+
+        if config.log_connection_info {
+            log::debug!("Redis URL: {}", Self::redact_url(&redis_url));
+        }
+
+        if !config.tls {
+            if config.ca_cert_path.is_some()
+                || config.client_cert_path.is_some()
+                || config.client_key_path.is_some()
+            {
+                return Err(ConnectionError::Configuration(
+                    "TLS certificates are configured but TLS is not enabled".to_string(),
+                ));
+            }
+            return Ok(redis::Client::open(redis_url)?);
+        }
 
-        Ok(client)
+        Self::open_tls(config, redis_url)
     }
 
-    /// 构建 Redis URL
-    pub fn build_redis_url(config: &RedisConfig) -> Result<String> {
-        if config.host.is_empty() {
+    /// 通过配置的 SOCKS5 代理建立多路复用连接
+    ///
+    /// `ConnectionManager` 只能自行建立 TCP 连接，无法经由代理，因此代理连接不会
+    /// 在断线后自动重连，调用方需要在连接错误后重新调用本方法。暂不支持经由代理的 TLS 连接。
+    ///
+    /// **注意**：`RedisUtils`、`Redis` 句柄及订阅者等封装都基于 `ConnectionManager` 或 `redis::Client`，
+    /// 无法使用代理连接。返回的连接只能配合 `redis::cmd(...).query_async(&mut conn)`
+    /// 或 `redis::AsyncCommands` 直接执行命令。
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Redis 配置信息，必须设置 `proxy`
+    ///
+    /// # Returns
+    ///
+    /// 返回已完成认证和选库的 MultiplexedConnection 实例或错误，
+    /// 超过 `connection_timeout` 仍未建立连接时返回超时错误
+    pub async fn proxied_connection(config: &RedisConfig) -> Result<MultiplexedConnection> {
+        let Some(proxy_config) = &config.proxy else {
+            return Err(ConnectionError::Configuration(
+                "No proxy configured".to_string(),
+            ));
+        };
+        if config.tls {
             return Err(ConnectionError::Configuration(
-                "Redis host cannot be empty".to_string(),
+                "TLS connections through a proxy are not supported".to_string(),
             ));
         }
 
+        let client = Self::open_direct(config)?;
+        // 代理无响应时连接和握手会一直挂起，整个建立过程受超时约束
+        let connect = Self::connect_via_proxy(config, proxy_config, &client);
+        tokio::time::timeout(config.connection_timeout, connect)
+            .await
+            .unwrap_or(Err(ConnectionError::Timeout))
+    }
+
+    /// 经由代理建立 TCP 连接并完成 Redis 握手
+    async fn connect_via_proxy(
+        config: &RedisConfig,
+        proxy_config: &ProxyConfig,
+        client: &redis::Client,
+    ) -> Result<MultiplexedConnection> {
+        let target = match config.addr {
+            Some(addr) => Target::Addr(addr),
+            None => Target::Host(&config.host, config.port),
+        };
+        let stream = proxy::connect(proxy_config, target).await?;
+        stream
+            .set_nodelay(config.tcp_nodelay)
+            .map_err(|e| ConnectionError::Network(e.to_string()))?;
+
+        let (mut conn, driver) = MultiplexedConnection::new_with_config(
+            &client.get_connection_info().redis,
+            stream,
+            AsyncConnectionConfig::new(),
+        )
+        .await?;
+        tokio::spawn(driver);
+
+        if config.readonly {
+            let _: () = redis::cmd("READONLY").query_async(&mut conn).await?;
+        }
+
+        Ok(conn)
+    }
+
+    #[cfg(feature = "tls")]
+    fn open_tls(config: &RedisConfig, redis_url: String) -> Result<redis::Client> {
+        // rustls 需要进程级的加密后端，未安装时首次握手会 panic；
+        // 已由应用或其他依赖安装时保留现有后端
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let ca_cert_path = config
+            .ca_cert_path
+            .clone()
+            .or_else(|| std::env::var_os(TLS_CA_CERT_ENV).map(PathBuf::from));
+
+        let client_tls = match (&config.client_cert_path, &config.client_key_path) {
+            (Some(cert), Some(key)) => Some(redis::ClientTlsConfig {
+                client_cert: Self::read_pem(cert)?,
+                client_key: Self::read_pem(key)?,
+            }),
+            (None, None) => None,
+            _ => {
+                return Err(ConnectionError::Configuration(
+                    "Client certificate and key must be configured together".to_string(),
+                ));
+            }
+        };
+
+        let root_cert = ca_cert_path.as_deref().map(Self::read_pem).transpose()?;
+        let certificates = redis::TlsCertificates {
+            client_tls,
+            root_cert,
+        };
+
+        redis::Client::build_with_tls(redis_url, certificates).map_err(|e| {
+            ConnectionError::Configuration(format!("Failed to load TLS certificates: {e}"))
+        })
+    }
+
+    #[cfg(not(feature = "tls"))]
+    fn open_tls(_config: &RedisConfig, _redis_url: String) -> Result<redis::Client> {
+        Err(ConnectionError::Configuration(
+            "TLS connections require the `tls` feature".to_string(),
+        ))
+    }
+
+    /// 读取 PEM 格式的证书或私钥文件
+    #[cfg_attr(not(feature = "tls"), allow(dead_code))]
+    fn read_pem(path: &Path) -> Result<Vec<u8>> {
+        std::fs::read(path).map_err(|e| {
+            ConnectionError::Configuration(format!(
+                "Failed to read certificate file {}: {e}",
+                path.display()
+            ))
+        })
+    }
+
+    /// 将 URL 中 `://:` 与 `@` 之间的密码替换为星号，用于日志输出
+    fn redact_url(url: &str) -> String {
+        let Some(start) = url.find("://:").map(|i| i + "://:".len()) else {
+            return url.to_string();
+        };
+        // 密码本身可能包含 '@'，以最后一个 '@' 作为分隔
+        match url.rfind('@').filter(|end| *end >= start) {
+            Some(end) => format!(
+                "{}{}{}",
+                &url[..start],
+                "*".repeat(end - start),
+                &url[end..]
+            ),
+            None => url.to_string(),
+        }
+    }
+
+    /// 构建 Redis URL
+    pub fn build_redis_url(config: &RedisConfig) -> Result<String> {
+        let address = match config.addr {
+            // SocketAddr 的 Display 会为 IPv6 地址自动加上方括号
+            Some(addr) => addr.to_string(),
+            None => {
+                if config.host.is_empty() {
+                    return Err(ConnectionError::Configuration(
+                        "Redis host cannot be empty".to_string(),
+                    ));
+                }
+                format!("{host}:{port}", host = config.host, port = config.port)
+            }
+        };
+
+        let scheme = if config.tls { "rediss" } else { "redis" };
         let redis_url = if config.password.is_empty() {
-            format!(
-                "redis://{host}:{port}/{db}",
-                host = config.host,
-                port = config.port,
-                db = config.db
-            )
+            format!("{scheme}://{address}/{db}", db = config.db)
         } else {
+            // 密码中的 `/`、`?`、`#`、`@`、`%` 等字符会破坏 URL 结构，需要转义
             format!(
-                "redis://:{password}@{host}:{port}/{db}",
-                password = config.password,
-                host = config.host,
-                port = config.port,
+                "{scheme}://:{password}@{address}/{db}",
+                password = Self::percent_encode(&config.password),
                 db = config.db
             )
         };
 
         Ok(redis_url)
     }
+
+    /// 对 URL 中的用户信息做百分号转义，只保留 RFC 3986 的非保留字符
+    fn percent_encode(value: &str) -> String {
+        let mut encoded = String::with_capacity(value.len());
+        for &byte in value.as_bytes() {
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+                encoded.push(char::from(byte));
+            } else {
+                encoded.push_str(&format!("%{byte:02X}"));
+            }
+        }
+        encoded
+    }
 }
@@ -1,7 +1,23 @@
 use crate::client::{RedisClient, RedisConfig};
-use crate::error::Result;
-use crate::pool::RedisPool;
-use redis::aio::ConnectionManager;
+use crate::error::{ConnectionError, Result};
+use crate::handle::Redis;
+use crate::keyevent::{EventType, KeyEventListener};
+use crate::pool::{ConnectionPool, PoolConfig, ReconnectingManager, RedisPool};
+use crate::proxy::ProxyConfig;
+use crate::pubsub::{
+    ClusterShardedSubscriber, LatestValueSubscriber, RedisSubscriber, ShardedSubscriber,
+    SubscriberConfig,
+};
+use crate::stream::StreamReader;
+use crate::types::Version;
+use crate::utils::RedisUtils;
+use redis::ToRedisArgs;
+use redis::aio::{ConnectionManager, MultiplexedConnection};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::OnceCell;
 
 /// Redis 连接器 - 统一的入口点，负责创建客户端和连接管理器
 #[derive(Debug, Clone)]
@@ -14,6 +30,30 @@ pub struct RedisConnector {
     pub password: String,
     /// Redis 数据库
     pub db: u8,
+    /// 预解析的 Redis 地址，设置后忽略 host 和 port
+    pub addr: Option<SocketAddr>,
+    /// 连接后是否发送 READONLY，用于在集群副本节点上执行读操作
+    pub readonly: bool,
+    /// TCP keepalive 探测的空闲时间
+    pub tcp_keepalive: Option<Duration>,
+    /// 是否开启 TCP_NODELAY
+    pub tcp_nodelay: bool,
+    /// 是否使用 TLS 连接（需要启用 `tls` 特性）
+    pub tls: bool,
+    /// 自定义 CA 证书（PEM）路径
+    pub ca_cert_path: Option<PathBuf>,
+    /// 双向 TLS 的客户端证书（PEM）路径
+    pub client_cert_path: Option<PathBuf>,
+    /// 双向 TLS 的客户端私钥（PEM）路径
+    pub client_key_path: Option<PathBuf>,
+    /// 创建客户端时是否记录连接地址（密码始终会被遮盖）
+    pub log_connection_info: bool,
+    /// SOCKS5 代理配置
+    pub proxy: Option<ProxyConfig>,
+    /// 连接池配置
+    pub pool_config: PoolConfig,
+    /// 首次探测到的服务器版本，在克隆之间共享
+    server_version: Arc<OnceCell<Version>>,
 }
 
 impl Default for RedisConnector {
@@ -23,6 +63,18 @@ impl Default for RedisConnector {
             port: 6379,
             password: "".to_string(),
             db: 0,
+            addr: None,
+            readonly: false,
+            tcp_keepalive: None,
+            tcp_nodelay: false,
+            tls: false,
+            ca_cert_path: None,
+            client_cert_path: None,
+            client_key_path: None,
+            log_connection_info: true,
+            proxy: None,
+            pool_config: PoolConfig::default(),
+            server_version: Arc::new(OnceCell::new()),
         }
     }
 }
@@ -37,34 +89,398 @@ impl RedisConnector {
         Self::default()
     }
 
+    /// 从连接 URL 创建连接器
+    ///
+    /// 支持 `redis://[:password@]host[:port][/db][?params]` 与 `rediss://`（启用 TLS）。
+    /// 可识别的查询参数：
+    ///
+    /// * `db` - 数据库编号，优先于路径中的编号
+    /// * `timeout` / `connect_timeout` - 连接超时，例如 `5s`、`500ms`、`1m`，纯数字按秒计算
+    /// * `pool_size` - [`RedisConnector::connection_pool`] 的连接数量
+    /// * `readonly` - 是否发送 READONLY（`true`/`false`）
+    ///
+    /// 未知参数会以 debug 级别记录后忽略。
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - 连接 URL
+    ///
+    /// # Returns
+    ///
+    /// 返回 RedisConnector 实例，URL 或已知参数的值不合法时返回配置错误
+    pub fn from_url(url: &str) -> Result<Self> {
+        // 错误信息中不包含 URL 本身，避免泄露密码
+        let parsed = redis::parse_redis_url(url)
+            .ok_or_else(|| ConnectionError::Configuration("Invalid Redis URL".to_string()))?;
+
+        let mut connector = Self::new();
+        match parsed.scheme() {
+            "redis" => {}
+            "rediss" => connector.tls = true,
+            other => {
+                return Err(ConnectionError::Configuration(format!(
+                    "Unsupported Redis URL scheme: {other}"
+                )));
+            }
+        }
+
+        let host = parsed
+            .host_str()
+            .filter(|host| !host.is_empty())
+            .ok_or_else(|| ConnectionError::Configuration("Redis URL has no host".to_string()))?;
+        connector.port = parsed.port().unwrap_or(connector.port);
+        // IPv6 地址在 URL 中带有方括号
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        match host.parse::<std::net::IpAddr>() {
+            Ok(ip) => connector.addr = Some(SocketAddr::new(ip, connector.port)),
+            Err(_) => connector.host = host.to_string(),
+        }
+
+        if let Some(password) = parsed.password() {
+            connector.password = Self::percent_decode(password)?;
+        }
+
+        let path = parsed.path().trim_start_matches('/');
+        if !path.is_empty() {
+            connector.db = path.parse().map_err(|_| {
+                ConnectionError::Configuration(format!("Invalid database in Redis URL: {path}"))
+            })?;
+        }
+
+        for (name, value) in parsed.query_pairs() {
+            let invalid = || {
+                ConnectionError::Configuration(format!(
+                    "Invalid value for Redis URL parameter {name}: {value}"
+                ))
+            };
+            match name.as_ref() {
+                "db" => connector.db = value.parse().map_err(|_| invalid())?,
+                "timeout" | "connect_timeout" => {
+                    connector.pool_config.connection_timeout =
+                        Self::parse_duration(&value).ok_or_else(invalid)?;
+                }
+                "pool_size" => {
+                    let size: usize = value.parse().map_err(|_| invalid())?;
+                    if size == 0 {
+                        return Err(invalid());
+                    }
+                    connector.pool_config.pool_size = Some(size);
+                }
+                "readonly" => connector.readonly = value.parse().map_err(|_| invalid())?,
+                _ => log::debug!("Ignoring unknown Redis URL parameter {name}"),
+            }
+        }
+
+        Ok(connector)
+    }
+
+    /// 解析 `5s`、`500ms`、`1m` 形式的时长，纯数字按秒计算
+    fn parse_duration(value: &str) -> Option<Duration> {
+        let (number, unit) = match value.find(|c: char| !c.is_ascii_digit()) {
+            Some(index) => value.split_at(index),
+            None => (value, "s"),
+        };
+        let number: u64 = number.parse().ok()?;
+        match unit {
+            "ms" => Some(Duration::from_millis(number)),
+            "s" => Some(Duration::from_secs(number)),
+            "m" => Some(Duration::from_secs(number.checked_mul(60)?)),
+            _ => None,
+        }
+    }
+
+    /// 解码 URL 中的百分号转义
+    fn percent_decode(value: &str) -> Result<String> {
+        let invalid =
+            || ConnectionError::Configuration("Invalid percent-encoding in Redis URL".to_string());
+
+        let mut bytes = Vec::with_capacity(value.len());
+        let mut rest = value.as_bytes();
+        while let Some((&byte, tail)) = rest.split_first() {
+            if byte == b'%' {
+                let hex = tail.get(..2).ok_or_else(invalid)?;
+                let hex = std::str::from_utf8(hex).map_err(|_| invalid())?;
+                bytes.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+                rest = &tail[2..];
+            } else {
+                bytes.push(byte);
+                rest = tail;
+            }
+        }
+        String::from_utf8(bytes).map_err(|_| invalid())
+    }
+
     /// 创建单个 Redis 客户端
     ///
     /// # Returns
     ///
     /// 返回 RedisClient 实例或错误
     pub fn client(&self) -> Result<redis::Client> {
-        RedisClient::create(RedisConfig {
-            host: self.host.clone(),
-            port: self.port,
-            password: self.password.clone(),
-            db: self.db,
-        })
+        RedisClient::create(self.redis_config())
+    }
+
+    /// 通过配置的 SOCKS5 代理建立多路复用连接，参见 [`RedisClient::proxied_connection`]
+    ///
+    /// # Returns
+    ///
+    /// 返回 MultiplexedConnection 实例或错误
+    pub async fn proxied_connection(&self) -> Result<MultiplexedConnection> {
+        RedisClient::proxied_connection(&self.redis_config()).await
     }
 
     /// 创建 Redis 连接管理器
     ///
+    /// 连接超过 `pool_config.connection_timeout` 视为超时。连接失败时立即返回错误，
+    /// 需要自动重试时请使用 [`RedisConnector::connection_manager_with_retry`]。
+    ///
     /// # Returns
     ///
     /// 返回 ConnectionManager 实例或错误
     pub async fn connection_manager(&self) -> Result<ConnectionManager> {
-        let redis_config = RedisConfig {
-            host: self.host.clone(),
-            port: self.port,
-            password: self.password.clone(),
-            db: self.db,
-        };
+        let connect = RedisPool::create(self.redis_config());
+        tokio::time::timeout(self.pool_config.connection_timeout, connect)
+            .await
+            .unwrap_or(Err(ConnectionError::Timeout))
+    }
+
+    /// 创建 Redis 连接管理器，连接错误时按退避间隔重试
+    ///
+    /// 连接失败时最多重试 `pool_config.max_retries` 次，
+    /// 每次重试前按 [`PoolConfig::retry_delay`] 等待带抖动的指数退避时间。
+    ///
+    /// # Returns
+    ///
+    /// 返回 ConnectionManager 实例或最后一次连接的错误
+    pub async fn connection_manager_with_retry(&self) -> Result<ConnectionManager> {
+        let mut attempt = 0;
+        loop {
+            match self.connection_manager().await {
+                Err(e) if e.is_connection_error() && attempt < self.pool_config.max_retries => {
+                    let delay = self.pool_config.retry_delay(attempt);
+                    log::debug!("Failed to connect to Redis, retrying in {delay:?}: {e}");
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// 创建 Redis 连接管理器并记录建立连接的耗时
+    ///
+    /// 耗时包含 DNS 解析、TCP 连接、认证及选择数据库等所有建连步骤。
+    ///
+    /// # Returns
+    ///
+    /// 返回 ConnectionManager 实例及建立连接的耗时，或错误
+    pub async fn connection_manager_timed(&self) -> Result<(ConnectionManager, Duration)> {
+        let started = Instant::now();
+        let manager = self.connection_manager().await?;
+        let elapsed = started.elapsed();
 
-        RedisPool::create(redis_config).await
+        log::debug!("Redis connection established in {elapsed:?}");
+
+        Ok((manager, elapsed))
+    }
+
+    /// 创建轮询分发的连接组，连接数量为 `pool_config.pool_size`，
+    /// 未设置时等于可用的 CPU 并行度；另外建立 `pool_config.dedicated_size` 条可独占借出的连接
+    ///
+    /// # Returns
+    ///
+    /// 返回 ConnectionPool 实例或错误
+    pub async fn connection_pool(&self) -> Result<ConnectionPool> {
+        let size = self
+            .pool_config
+            .pool_size
+            .unwrap_or_else(ConnectionPool::default_size);
+        self.connection_pool_with_size(size).await
+    }
+
+    /// 创建指定连接数量的轮询连接组，可独占借出的连接数量仍由 `pool_config.dedicated_size` 决定
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - 轮询分发的连接数量，必须大于 0
+    ///
+    /// # Returns
+    ///
+    /// 返回 ConnectionPool 实例或错误
+    pub async fn connection_pool_with_size(&self, size: usize) -> Result<ConnectionPool> {
+        let connects = (0..size).map(|_| self.connection_manager());
+        let managers = futures_util::future::try_join_all(connects).await?;
+        let connects = (0..self.pool_config.dedicated_size).map(|_| self.connection_manager());
+        let dedicated = futures_util::future::try_join_all(connects).await?;
+        Ok(ConnectionPool::with_dedicated(managers, dedicated)?.with_connector(self.clone()))
+    }
+
+    /// 获取服务器版本，首次调用时建立连接探测，之后直接返回缓存的结果
+    ///
+    /// 缓存在连接器的所有克隆之间共享。
+    ///
+    /// # Returns
+    ///
+    /// 返回可比较的服务器版本或错误
+    pub async fn server_version(&self) -> Result<Version> {
+        let version = self
+            .server_version
+            .get_or_try_init(|| async {
+                let mut conn = self.connection_manager().await?;
+                RedisUtils::server_version(&mut conn).await
+            })
+            .await?;
+        Ok(*version)
+    }
+
+    /// 创建可重建的连接管理器
+    ///
+    /// 连续失败次数达到 `pool_config.max_retries` 后，会通过本连接器重新建立连接。
+    ///
+    /// # Returns
+    ///
+    /// 返回 ReconnectingManager 实例或错误
+    pub async fn reconnecting_manager(&self) -> Result<ReconnectingManager> {
+        ReconnectingManager::new(self.clone()).await
+    }
+
+    /// 创建 Redis 句柄
+    ///
+    /// # Returns
+    ///
+    /// 返回持有连接管理器的 Redis 句柄或错误
+    pub async fn connection(&self) -> Result<Redis> {
+        let manager = self.connection_manager().await?;
+        Ok(Redis::new(manager))
+    }
+
+    /// 创建 Redis 订阅者
+    ///
+    /// # Arguments
+    ///
+    /// * `channels` - 要订阅的频道列表
+    /// * `patterns` - 要订阅的频道模式列表
+    /// * `config` - 订阅者配置
+    ///
+    /// # Returns
+    ///
+    /// 返回 RedisSubscriber 实例或错误
+    pub async fn subscriber<C, P>(
+        &self,
+        channels: &[C],
+        patterns: &[P],
+        config: SubscriberConfig,
+    ) -> Result<RedisSubscriber>
+    where
+        C: ToRedisArgs + Send + Sync,
+        P: ToRedisArgs + Send + Sync,
+    {
+        RedisSubscriber::create(self.client()?, channels, patterns, config).await
+    }
+
+    /// 创建只保留每个频道最新消息的订阅者
+    ///
+    /// # Arguments
+    ///
+    /// * `channels` - 要订阅的频道列表
+    /// * `patterns` - 要订阅的频道模式列表
+    ///
+    /// # Returns
+    ///
+    /// 返回 LatestValueSubscriber 实例或错误
+    pub async fn latest_value_subscriber<C, P>(
+        &self,
+        channels: &[C],
+        patterns: &[P],
+    ) -> Result<LatestValueSubscriber>
+    where
+        C: ToRedisArgs + Send + Sync,
+        P: ToRedisArgs + Send + Sync,
+    {
+        LatestValueSubscriber::create(self.client()?, channels, patterns).await
+    }
+
+    /// 创建分片发布订阅的订阅者（Redis 7.0+）
+    ///
+    /// 连接器必须指向负责这些频道所属槽的节点。
+    ///
+    /// # Arguments
+    ///
+    /// * `channels` - 要订阅的分片频道列表
+    ///
+    /// # Returns
+    ///
+    /// 返回 ShardedSubscriber 实例或错误
+    pub async fn sharded_subscriber<C>(&self, channels: &[C]) -> Result<ShardedSubscriber>
+    where
+        C: ToRedisArgs + Send + Sync,
+    {
+        ShardedSubscriber::create(self.client()?, channels).await
+    }
+
+    /// 创建集群感知的分片订阅者（Redis 7.0+）
+    ///
+    /// 连接器可以指向集群中的任意节点，订阅者会自动连接到负责频道所属槽的节点，
+    /// 并在槽位迁移后重新路由。
+    ///
+    /// # Arguments
+    ///
+    /// * `channels` - 要订阅的分片频道列表，必须位于同一个槽
+    ///
+    /// # Returns
+    ///
+    /// 返回 ClusterShardedSubscriber 实例或错误
+    pub async fn cluster_sharded_subscriber<S>(
+        &self,
+        channels: &[S],
+    ) -> Result<ClusterShardedSubscriber>
+    where
+        S: AsRef<str>,
+    {
+        ClusterShardedSubscriber::create(self.client()?, channels, self.pool_config.clone()).await
+    }
+
+    /// 监听匹配模式的键上发生的事件，并在后台调用回调
+    ///
+    /// 会尝试在服务器上开启所需的键空间通知类别，CONFIG 命令被禁用时记录 debug 日志后继续订阅，
+    /// 此时需要服务器已开启对应的通知。订阅断开后自动重连。
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - 键匹配模式，例如 `cache:*`
+    /// * `event` - 要监听的事件类型
+    /// * `handler` - 回调，参数为发生事件的键名
+    ///
+    /// # Returns
+    ///
+    /// 返回监听器，丢弃即停止监听
+    pub async fn on_key_event<F>(
+        &self,
+        pattern: &str,
+        event: EventType,
+        handler: F,
+    ) -> Result<KeyEventListener>
+    where
+        F: Fn(String) + Send + Sync + 'static,
+    {
+        KeyEventListener::start(self.clone(), pattern, event, handler).await
+    }
+
+    /// 创建断线后可续读的流读取器
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 流键名
+    /// * `start_id` - 从该 ID 之后开始读取，`"$"` 表示只读取新消息
+    ///
+    /// # Returns
+    ///
+    /// 返回 StreamReader 实例，连接在首次读取时建立
+    pub fn stream_reader(
+        &self,
+        key: impl Into<String>,
+        start_id: impl Into<String>,
+    ) -> StreamReader {
+        StreamReader::new(self.clone(), key, start_id)
     }
 
     /// 设置主机
@@ -90,4 +506,99 @@ impl RedisConnector {
         self.db = db;
         self
     }
+
+    /// 设置预解析的地址，绕过客户端内部的 DNS 解析
+    pub fn addr(mut self, addr: SocketAddr) -> Self {
+        self.addr = Some(addr);
+        self
+    }
+
+    /// 设置连接后是否发送 READONLY
+    ///
+    /// 连接到集群副本节点做读扩展时需要开启，否则读请求会被重定向到主节点。
+    /// `ConnectionManager` 自动重连后不会重新发送 READONLY，需要长期保持时请使用
+    /// [`RedisConnector::reconnecting_manager`]。
+    pub fn readonly(mut self, readonly: bool) -> Self {
+        self.readonly = readonly;
+        self
+    }
+
+    /// 设置 TCP keepalive 探测的空闲时间
+    ///
+    /// 连接空闲超过该时间后由内核发送探测包，用于发现被中间设备静默丢弃的半开连接。
+    pub fn tcp_keepalive(mut self, idle: Duration) -> Self {
+        self.tcp_keepalive = Some(idle);
+        self
+    }
+
+    /// 设置是否开启 TCP_NODELAY
+    pub fn tcp_nodelay(mut self, nodelay: bool) -> Self {
+        self.tcp_nodelay = nodelay;
+        self
+    }
+
+    /// 设置是否使用 TLS 连接（需要启用 `tls` 特性）
+    pub fn tls(mut self, tls: bool) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    /// 设置用于校验服务器证书的 CA 证书路径，并启用 TLS
+    pub fn ca_cert_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.ca_cert_path = Some(path.into());
+        self.tls = true;
+        self
+    }
+
+    /// 设置双向 TLS 的客户端证书与私钥路径，并启用 TLS
+    pub fn client_cert(
+        mut self,
+        cert_path: impl Into<PathBuf>,
+        key_path: impl Into<PathBuf>,
+    ) -> Self {
+        self.client_cert_path = Some(cert_path.into());
+        self.client_key_path = Some(key_path.into());
+        self.tls = true;
+        self
+    }
+
+    /// 设置创建客户端时是否记录连接地址
+    pub fn log_connection_info(mut self, enabled: bool) -> Self {
+        self.log_connection_info = enabled;
+        self
+    }
+
+    /// 设置 SOCKS5 代理，之后只能通过 [`RedisConnector::proxied_connection`] 建立连接，
+    /// 其余创建客户端或连接管理器的方法都会返回配置错误
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// 设置连接池配置
+    pub fn pool_config(mut self, pool_config: PoolConfig) -> Self {
+        self.pool_config = pool_config;
+        self
+    }
+
+    /// 根据连接器字段构建 Redis 配置
+    fn redis_config(&self) -> RedisConfig {
+        RedisConfig {
+            host: self.host.clone(),
+            port: self.port,
+            password: self.password.clone(),
+            db: self.db,
+            addr: self.addr,
+            readonly: self.readonly,
+            tcp_keepalive: self.tcp_keepalive,
+            tcp_nodelay: self.tcp_nodelay,
+            tls: self.tls,
+            ca_cert_path: self.ca_cert_path.clone(),
+            client_cert_path: self.client_cert_path.clone(),
+            client_key_path: self.client_key_path.clone(),
+            log_connection_info: self.log_connection_info,
+            proxy: self.proxy.clone(),
+            connection_timeout: self.pool_config.connection_timeout,
+        }
+    }
 }
@@ -0,0 +1,122 @@
+use crate::error::{ConnectionError, Result};
+use crate::utils::RedisUtils;
+use redis::aio::ConnectionManager;
+use redis::{Cmd, FromRedisValue, ToRedisArgs};
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::Duration;
+
+/// 单次调用的超时守卫 - 仅对通过它发起的操作施加超时
+///
+/// 通过 [`RedisUtils::with_deadline`] 创建。超时后操作被放弃并返回
+/// [`ConnectionError::Timeout`]，迟到的回复会被连接丢弃，不影响后续命令。
+pub struct Deadline<'a> {
+    conn: &'a mut ConnectionManager,
+    timeout: Duration,
+}
+
+impl<'a> Deadline<'a> {
+    /// 创建超时守卫
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - Redis 连接管理器
+    /// * `timeout` - 本次操作允许的最长耗时
+    ///
+    /// # Returns
+    ///
+    /// 返回 Deadline 实例
+    pub fn new(conn: &'a mut ConnectionManager, timeout: Duration) -> Self {
+        Self { conn, timeout }
+    }
+
+    /// 在超时限制内等待操作完成
+    async fn run<T>(timeout: Duration, op: impl Future<Output = Result<T>>) -> Result<T> {
+        tokio::time::timeout(timeout, op)
+            .await
+            .map_err(|_| ConnectionError::Timeout)?
+    }
+
+    /// 在超时限制内执行任意命令
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - 要执行的命令
+    ///
+    /// # Returns
+    ///
+    /// 返回命令结果，超时返回 [`ConnectionError::Timeout`]
+    pub async fn query<T: FromRedisValue>(self, cmd: &Cmd) -> Result<T> {
+        let conn = self.conn;
+        Self::run(
+            self.timeout,
+            async move { Ok(cmd.query_async(conn).await?) },
+        )
+        .await
+    }
+
+    /// 在超时限制内获取字符串值，参见 [`RedisUtils::get`]
+    pub async fn get<K, V>(self, key: K) -> Result<Option<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        Self::run(self.timeout, RedisUtils::get(self.conn, key)).await
+    }
+
+    /// 在超时限制内设置字符串值，参见 [`RedisUtils::set`]
+    pub async fn set<K, V>(self, key: K, value: V) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        Self::run(self.timeout, RedisUtils::set(self.conn, key, value)).await
+    }
+
+    /// 在超时限制内删除键，参见 [`RedisUtils::del`]
+    pub async fn del<K>(self, keys: K) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        Self::run(self.timeout, RedisUtils::del(self.conn, keys)).await
+    }
+
+    /// 在超时限制内检查键是否存在，参见 [`RedisUtils::exists`]
+    pub async fn exists<K>(self, key: K) -> Result<bool>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        Self::run(self.timeout, RedisUtils::exists(self.conn, key)).await
+    }
+
+    /// 在超时限制内获取哈希表字段值，参见 [`RedisUtils::hget`]
+    pub async fn hget<K, F, V>(self, key: K, field: F) -> Result<Option<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        F: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        Self::run(self.timeout, RedisUtils::hget(self.conn, key, field)).await
+    }
+
+    /// 在超时限制内获取哈希表所有字段和值，参见 [`RedisUtils::hgetall`]
+    pub async fn hgetall<K>(self, key: K) -> Result<HashMap<String, String>>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        Self::run(self.timeout, RedisUtils::hgetall(self.conn, key)).await
+    }
+
+    /// 在超时限制内获取有序集合指定范围的成员，参见 [`RedisUtils::zrange`]
+    pub async fn zrange<K, V>(self, key: K, start: isize, stop: isize) -> Result<Vec<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        Self::run(
+            self.timeout,
+            RedisUtils::zrange(self.conn, key, start, stop),
+        )
+        .await
+    }
+}
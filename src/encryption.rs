@@ -0,0 +1,121 @@
+use crate::error::{ConnectionError, Result};
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use std::fmt;
+
+/// 密文格式版本
+const FORMAT_VERSION: u8 = 1;
+/// 头部长度：格式版本 + 密钥版本
+const HEADER_LEN: usize = 2;
+/// AES-GCM 随机数长度
+const NONCE_LEN: usize = 12;
+
+/// 静态加密使用的 AES-256-GCM 密钥
+///
+/// 每个密钥带有一个版本号，写入时记录在密文头部，读取时按版本号选择解密密钥。
+/// 轮换密钥时用新版本号的密钥写入，同时在读取的密钥列表中保留旧密钥，
+/// 直到旧数据全部过期或被重写。
+///
+/// 密文格式为 `[格式版本][密钥版本][12 字节随机数][密文与认证标签]`，
+/// 头部与 Redis 键名一起作为附加认证数据，因此密文被篡改、改写版本号或复制到其他键下都会解密失败。
+#[derive(Clone)]
+pub struct EncryptionKey {
+    version: u8,
+    cipher: Aes256Gcm,
+}
+
+impl EncryptionKey {
+    /// 创建密钥
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - 密钥版本号，用于轮换
+    /// * `secret` - 32 字节的密钥材料
+    ///
+    /// # Returns
+    ///
+    /// 返回 EncryptionKey 实例
+    pub fn new(version: u8, secret: &[u8; 32]) -> Self {
+        Self {
+            version,
+            cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(secret)),
+        }
+    }
+
+    /// 获取密钥版本号
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// 加密明文，`aad` 为额外绑定的认证数据（通常是 Redis 键名）
+    pub(crate) fn encrypt(&self, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        let header = [FORMAT_VERSION, self.version];
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: plaintext,
+                    aad: &[&header[..], aad].concat(),
+                },
+            )
+            .map_err(|_| ConnectionError::Serialization("encryption failed".to_string()))?;
+
+        let mut output = Vec::with_capacity(HEADER_LEN + NONCE_LEN + ciphertext.len());
+        output.extend_from_slice(&header);
+        output.extend_from_slice(&nonce);
+        output.extend_from_slice(&ciphertext);
+        Ok(output)
+    }
+
+    /// 按密文头部的版本号从 `keys` 中选择密钥并解密
+    pub(crate) fn decrypt(keys: &[EncryptionKey], data: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        if data.len() < HEADER_LEN + NONCE_LEN {
+            return Err(ConnectionError::Deserialization(
+                "encrypted value is truncated".to_string(),
+            ));
+        }
+        let (header, rest) = data.split_at(HEADER_LEN);
+        if header[0] != FORMAT_VERSION {
+            return Err(ConnectionError::Deserialization(format!(
+                "unsupported encrypted value format: {}",
+                header[0]
+            )));
+        }
+
+        let key = keys
+            .iter()
+            .find(|key| key.version == header[1])
+            .ok_or_else(|| {
+                ConnectionError::Deserialization(format!(
+                    "no encryption key for version {}",
+                    header[1]
+                ))
+            })?;
+
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+        key.cipher
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: &[header, aad].concat(),
+                },
+            )
+            .map_err(|_| {
+                ConnectionError::Deserialization(
+                    "decryption failed: wrong key or tampered data".to_string(),
+                )
+            })
+    }
+}
+
+impl fmt::Debug for EncryptionKey {
+    // 不输出密钥材料
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncryptionKey")
+            .field("version", &self.version)
+            .finish_non_exhaustive()
+    }
+}
@@ -21,6 +21,14 @@ pub enum ConnectionError {
     Serialization(String),
     /// 反序列化错误
     Deserialization(String),
+    /// Lua 脚本错误
+    Script(String),
+    /// 故障转移失败（已有故障转移在进行中或被中止）
+    Failover(String),
+    /// 类型不匹配（对键执行了与其类型不符的命令，或回复无法转换为目标类型）
+    WrongType(String),
+    /// 已有后台持久化（BGSAVE 或 AOF 重写）在进行中，可稍后重试
+    SaveInProgress(String),
 }
 
 impl fmt::Display for ConnectionError {
@@ -39,14 +47,42 @@ impl fmt::Display for ConnectionError {
             ConnectionError::Network(msg) => write!(f, "Network error: {msg}"),
             ConnectionError::Serialization(msg) => write!(f, "Serialization error: {msg}"),
             ConnectionError::Deserialization(msg) => write!(f, "Deserialization error: {msg}"),
+            ConnectionError::Script(msg) => write!(f, "Script error: {msg}"),
+            ConnectionError::Failover(msg) => write!(f, "Failover error: {msg}"),
+            ConnectionError::WrongType(msg) => write!(f, "Wrong type: {msg}"),
+            ConnectionError::SaveInProgress(msg) => write!(f, "Save in progress: {msg}"),
         }
     }
 }
 
 impl std::error::Error for ConnectionError {}
 
+impl ConnectionError {
+    /// 判断错误是否由连接不可用引起（IO 错误、连接断开、连接被拒绝或超时）
+    pub fn is_connection_error(&self) -> bool {
+        match self {
+            ConnectionError::ClientCreation(e)
+            | ConnectionError::ConnectionAcquisition(e)
+            | ConnectionError::ConnectionManager(e) => {
+                e.is_io_error()
+                    || e.is_connection_dropped()
+                    || e.is_connection_refusal()
+                    || e.is_timeout()
+            }
+            ConnectionError::PoolCreation(_)
+            | ConnectionError::Timeout
+            | ConnectionError::Network(_) => true,
+            _ => false,
+        }
+    }
+}
+
 impl From<redis::RedisError> for ConnectionError {
     fn from(err: redis::RedisError) -> Self {
+        // 服务端的 WRONGTYPE 回复没有专门的 ErrorKind，需要按错误码识别
+        if err.kind() == redis::ErrorKind::TypeError || err.code() == Some("WRONGTYPE") {
+            return ConnectionError::WrongType(err.to_string());
+        }
         ConnectionError::ClientCreation(err)
     }
 }
@@ -0,0 +1,508 @@
+use crate::error::Result;
+use crate::types::{End, SetOptions, SetResponse};
+use crate::utils::RedisUtils;
+use redis::aio::ConnectionManager;
+use redis::{FromRedisValue, ToRedisArgs};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Redis 句柄 - 持有 ConnectionManager，以实例方法的形式调用 [`RedisUtils`]
+///
+/// `ConnectionManager` 的克隆开销很小且共享同一底层连接，
+/// 因此每次调用都会克隆一份连接管理器，方法只需要 `&self`。
+#[derive(Clone)]
+pub struct Redis {
+    conn: ConnectionManager,
+}
+
+impl Redis {
+    /// 使用已有的连接管理器创建句柄
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - Redis 连接管理器
+    ///
+    /// # Returns
+    ///
+    /// 返回 Redis 句柄
+    pub fn new(conn: ConnectionManager) -> Self {
+        Self { conn }
+    }
+
+    /// 获取内部连接管理器的克隆，便于直接调用 [`RedisUtils`] 的静态方法
+    pub fn connection_manager(&self) -> ConnectionManager {
+        self.conn.clone()
+    }
+
+    // ==================== 字符串操作 ====================
+
+    /// 设置字符串值，参见 [`RedisUtils::set`]
+    pub async fn set<K, V>(&self, key: K, value: V) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::set(&mut self.conn.clone(), key, value).await
+    }
+
+    /// 设置字符串值并指定过期时间，参见 [`RedisUtils::setex`]
+    pub async fn setex<K, V>(&self, key: K, value: V, seconds: usize) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::setex(&mut self.conn.clone(), key, value, seconds).await
+    }
+
+    /// 设置字符串值并指定毫秒级过期时间，参见 [`RedisUtils::psetex`]
+    pub async fn psetex<K, V>(&self, key: K, value: V, millis: u64) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::psetex(&mut self.conn.clone(), key, value, millis).await
+    }
+
+    /// 设置字符串值并以 Duration 指定过期时间，参见 [`RedisUtils::set_duration`]
+    pub async fn set_duration<K, V>(&self, key: K, value: V, ttl: Duration) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::set_duration(&mut self.conn.clone(), key, value, ttl).await
+    }
+
+    /// 按完整选项执行 SET 命令，参见 [`RedisUtils::set_opts`]
+    pub async fn set_opts<K, V>(&self, key: K, value: V, options: SetOptions) -> Result<SetResponse>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::set_opts(&mut self.conn.clone(), key, value, options).await
+    }
+
+    /// 仅当键不存在时写入并返回是否为首次写入，参见 [`RedisUtils::set_if_absent`]
+    pub async fn set_if_absent<K, V>(&self, key: K, value: V, ttl: Option<Duration>) -> Result<bool>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::set_if_absent(&mut self.conn.clone(), key, value, ttl).await
+    }
+
+    /// 获取字符串值，参见 [`RedisUtils::get`]
+    pub async fn get<K, V>(&self, key: K) -> Result<Option<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        RedisUtils::get(&mut self.conn.clone(), key).await
+    }
+
+    /// 以浮点数增量递增键的值，参见 [`RedisUtils::incr_by_float`]
+    pub async fn incr_by_float<K>(&self, key: K, delta: f64) -> Result<f64>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::incr_by_float(&mut self.conn.clone(), key, delta).await
+    }
+
+    /// 删除键，参见 [`RedisUtils::del`]
+    pub async fn del<K>(&self, keys: K) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::del(&mut self.conn.clone(), keys).await
+    }
+
+    /// 检查键是否存在，参见 [`RedisUtils::exists`]
+    pub async fn exists<K>(&self, key: K) -> Result<bool>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::exists(&mut self.conn.clone(), key).await
+    }
+
+    /// 设置键的过期时间，参见 [`RedisUtils::expire`]
+    pub async fn expire<K>(&self, key: K, seconds: usize) -> Result<bool>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::expire(&mut self.conn.clone(), key, seconds).await
+    }
+
+    /// 获取键的剩余生存时间，参见 [`RedisUtils::ttl`]
+    pub async fn ttl<K>(&self, key: K) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::ttl(&mut self.conn.clone(), key).await
+    }
+
+    /// 批量获取多个键的剩余生存时间，参见 [`RedisUtils::ttl_many`]
+    pub async fn ttl_many<K>(&self, keys: &[K]) -> Result<Vec<i64>>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::ttl_many(&mut self.conn.clone(), keys).await
+    }
+
+    // ==================== 哈希表操作 ====================
+
+    /// 设置哈希表字段值，参见 [`RedisUtils::hset`]
+    pub async fn hset<K, F, V>(&self, key: K, field: F, value: V) -> Result<bool>
+    where
+        K: ToRedisArgs + Send + Sync,
+        F: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::hset(&mut self.conn.clone(), key, field, value).await
+    }
+
+    /// 批量设置哈希表字段值，参见 [`RedisUtils::hset_multiple`]
+    pub async fn hset_multiple<K, F, V>(&self, key: K, pairs: &[(F, V)]) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        F: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::hset_multiple(&mut self.conn.clone(), key, pairs).await
+    }
+
+    /// 获取哈希表字段值，参见 [`RedisUtils::hget`]
+    pub async fn hget<K, F, V>(&self, key: K, field: F) -> Result<Option<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        F: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        RedisUtils::hget(&mut self.conn.clone(), key, field).await
+    }
+
+    /// 获取哈希表所有字段和值，参见 [`RedisUtils::hgetall`]
+    pub async fn hgetall<K>(&self, key: K) -> Result<HashMap<String, String>>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::hgetall(&mut self.conn.clone(), key).await
+    }
+
+    /// 删除哈希表字段，参见 [`RedisUtils::hdel`]
+    pub async fn hdel<K, F>(&self, key: K, fields: F) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        F: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::hdel(&mut self.conn.clone(), key, fields).await
+    }
+
+    /// 检查哈希表字段是否存在，参见 [`RedisUtils::hexists`]
+    pub async fn hexists<K, F>(&self, key: K, field: F) -> Result<bool>
+    where
+        K: ToRedisArgs + Send + Sync,
+        F: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::hexists(&mut self.conn.clone(), key, field).await
+    }
+
+    /// 随机获取哈希表中的字段，参见 [`RedisUtils::hrandfield`]
+    pub async fn hrandfield<K>(
+        &self,
+        key: K,
+        count: i64,
+        with_values: bool,
+    ) -> Result<Vec<(String, Option<String>)>>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::hrandfield(&mut self.conn.clone(), key, count, with_values).await
+    }
+
+    // ==================== 列表操作 ====================
+
+    /// 向列表左侧推入元素，参见 [`RedisUtils::lpush`]
+    pub async fn lpush<K, V>(&self, key: K, values: V) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::lpush(&mut self.conn.clone(), key, values).await
+    }
+
+    /// 向列表右侧推入元素，参见 [`RedisUtils::rpush`]
+    pub async fn rpush<K, V>(&self, key: K, values: V) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::rpush(&mut self.conn.clone(), key, values).await
+    }
+
+    /// 推入元素并将列表裁剪到指定的最大长度，参见 [`RedisUtils::push_capped`]
+    pub async fn push_capped<K, V>(
+        &self,
+        key: K,
+        value: V,
+        max_len: usize,
+        side: End,
+    ) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::push_capped(&mut self.conn.clone(), key, value, max_len, side).await
+    }
+
+    /// 从列表左侧弹出元素，参见 [`RedisUtils::lpop`]
+    pub async fn lpop<K, V>(&self, key: K) -> Result<Option<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        RedisUtils::lpop(&mut self.conn.clone(), key).await
+    }
+
+    /// 从列表右侧弹出元素，参见 [`RedisUtils::rpop`]
+    pub async fn rpop<K, V>(&self, key: K) -> Result<Option<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        RedisUtils::rpop(&mut self.conn.clone(), key).await
+    }
+
+    /// 从列表左侧一次弹出多个元素，参见 [`RedisUtils::lpop_count`]
+    pub async fn lpop_count<K, V>(&self, key: K, count: usize) -> Result<Vec<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        RedisUtils::lpop_count(&mut self.conn.clone(), key, count).await
+    }
+
+    /// 从列表右侧一次弹出多个元素，参见 [`RedisUtils::rpop_count`]
+    pub async fn rpop_count<K, V>(&self, key: K, count: usize) -> Result<Vec<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        RedisUtils::rpop_count(&mut self.conn.clone(), key, count).await
+    }
+
+    /// 获取列表长度，参见 [`RedisUtils::llen`]
+    pub async fn llen<K>(&self, key: K) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::llen(&mut self.conn.clone(), key).await
+    }
+
+    /// 获取列表指定范围的元素，参见 [`RedisUtils::lrange`]
+    pub async fn lrange<K, V>(&self, key: K, start: isize, stop: isize) -> Result<Vec<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        RedisUtils::lrange(&mut self.conn.clone(), key, start, stop).await
+    }
+
+    // ==================== 集合操作 ====================
+
+    /// 向集合添加成员，参见 [`RedisUtils::sadd`]
+    pub async fn sadd<K, M>(&self, key: K, members: M) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::sadd(&mut self.conn.clone(), key, members).await
+    }
+
+    /// 从集合移除成员，参见 [`RedisUtils::srem`]
+    pub async fn srem<K, M>(&self, key: K, members: M) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::srem(&mut self.conn.clone(), key, members).await
+    }
+
+    /// 检查成员是否在集合中，参见 [`RedisUtils::sismember`]
+    pub async fn sismember<K, M>(&self, key: K, member: M) -> Result<bool>
+    where
+        K: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::sismember(&mut self.conn.clone(), key, member).await
+    }
+
+    /// 获取集合所有成员，参见 [`RedisUtils::smembers`]
+    pub async fn smembers<K, V>(&self, key: K) -> Result<Vec<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        RedisUtils::smembers(&mut self.conn.clone(), key).await
+    }
+
+    /// 获取集合所有成员并收集到指定的集合类型中，参见 [`RedisUtils::smembers_collect`]
+    pub async fn smembers_collect<K, C>(&self, key: K) -> Result<C>
+    where
+        K: ToRedisArgs + Send + Sync,
+        C: FromIterator<String>,
+    {
+        RedisUtils::smembers_collect(&mut self.conn.clone(), key).await
+    }
+
+    /// 获取集合成员数量，参见 [`RedisUtils::scard`]
+    pub async fn scard<K>(&self, key: K) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::scard(&mut self.conn.clone(), key).await
+    }
+
+    // ==================== 有序集合操作 ====================
+
+    /// 向有序集合添加成员，参见 [`RedisUtils::zadd`]
+    pub async fn zadd<K, S, M>(&self, key: K, score: S, member: M) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        S: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::zadd(&mut self.conn.clone(), key, score, member).await
+    }
+
+    /// 从有序集合移除成员，参见 [`RedisUtils::zrem`]
+    pub async fn zrem<K, M>(&self, key: K, members: M) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::zrem(&mut self.conn.clone(), key, members).await
+    }
+
+    /// 获取有序集合指定范围的成员，参见 [`RedisUtils::zrange`]
+    pub async fn zrange<K, V>(&self, key: K, start: isize, stop: isize) -> Result<Vec<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        RedisUtils::zrange(&mut self.conn.clone(), key, start, stop).await
+    }
+
+    /// 获取有序集合成员数量，参见 [`RedisUtils::zcard`]
+    pub async fn zcard<K>(&self, key: K) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        RedisUtils::zcard(&mut self.conn.clone(), key).await
+    }
+
+    // ==================== JSON / 结构体操作 ====================
+
+    /// 设置 JSON 对象，参见 [`RedisUtils::set_json`]
+    pub async fn set_json<K, V>(&self, key: K, value: &V) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: Serialize,
+    {
+        RedisUtils::set_json(&mut self.conn.clone(), key, value).await
+    }
+
+    /// 获取 JSON 对象，参见 [`RedisUtils::get_json`]
+    pub async fn get_json<K, V>(&self, key: K) -> Result<Option<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: for<'de> Deserialize<'de>,
+    {
+        RedisUtils::get_json(&mut self.conn.clone(), key).await
+    }
+
+    /// 设置任意结构体对象，参见 [`RedisUtils::set_struct`]
+    pub async fn set_struct<K, T>(&self, key: K, value: &T) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        T: Serialize,
+    {
+        RedisUtils::set_struct(&mut self.conn.clone(), key, value).await
+    }
+
+    /// 设置任意结构体对象并指定过期时间，参见 [`RedisUtils::set_struct_ex`]
+    pub async fn set_struct_ex<K, T>(&self, key: K, value: &T, seconds: usize) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        T: Serialize,
+    {
+        RedisUtils::set_struct_ex(&mut self.conn.clone(), key, value, seconds).await
+    }
+
+    /// 获取任意结构体对象，参见 [`RedisUtils::get_struct`]
+    pub async fn get_struct<K, T>(&self, key: K) -> Result<Option<T>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        T: for<'de> Deserialize<'de>,
+    {
+        RedisUtils::get_struct(&mut self.conn.clone(), key).await
+    }
+
+    /// 批量获取值，参见 [`RedisUtils::mget`]
+    pub async fn mget<K>(&self, keys: &[K]) -> Result<Vec<Option<String>>>
+    where
+        K: ToRedisArgs + Clone + Send + Sync,
+    {
+        RedisUtils::mget(&mut self.conn.clone(), keys).await
+    }
+
+    /// 批量获取结构体对象，参见 [`RedisUtils::mget_struct`]
+    pub async fn mget_struct<K, T>(&self, keys: &[K]) -> Result<Vec<Option<T>>>
+    where
+        K: ToRedisArgs + Clone + Send + Sync,
+        T: for<'de> Deserialize<'de>,
+    {
+        RedisUtils::mget_struct(&mut self.conn.clone(), keys).await
+    }
+
+    /// 获取列表指定范围的元素并解析为结构体对象，参见 [`RedisUtils::lrange_struct`]
+    pub async fn lrange_struct<K, T>(&self, key: K, start: isize, stop: isize) -> Result<Vec<T>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        T: DeserializeOwned,
+    {
+        RedisUtils::lrange_struct(&mut self.conn.clone(), key, start, stop).await
+    }
+
+    /// 将结构体按字段存储为哈希表，参见 [`RedisUtils::hset_struct`]
+    pub async fn hset_struct<K, T>(&self, key: K, value: &T) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        T: Serialize,
+    {
+        RedisUtils::hset_struct(&mut self.conn.clone(), key, value).await
+    }
+
+    /// 读取按字段存储的哈希表并还原为结构体，参见 [`RedisUtils::hget_struct`]
+    pub async fn hget_struct<K, T>(&self, key: K) -> Result<Option<T>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        T: DeserializeOwned,
+    {
+        RedisUtils::hget_struct(&mut self.conn.clone(), key).await
+    }
+
+    /// 批量读取按字段存储的哈希表，参见 [`RedisUtils::hgetall_struct_multi`]
+    pub async fn hgetall_struct_multi<T>(&self, keys: &[String]) -> Result<HashMap<String, T>>
+    where
+        T: DeserializeOwned,
+    {
+        RedisUtils::hgetall_struct_multi(&mut self.conn.clone(), keys).await
+    }
+}
+
+impl From<ConnectionManager> for Redis {
+    fn from(conn: ConnectionManager) -> Self {
+        Self::new(conn)
+    }
+}
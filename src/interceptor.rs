@@ -0,0 +1,154 @@
+use crate::error::{ConnectionError, Result};
+use redis::aio::ConnectionManager;
+use redis::{Arg, Cmd, FromRedisValue};
+use std::future::Future;
+use std::sync::Arc;
+
+/// 命令拦截器 - 在每个操作前后执行的横切逻辑（指标、熔断、模拟注入等）
+pub trait Interceptor: Send + Sync {
+    /// 操作执行前调用，返回错误时跳过该操作并直接返回该错误
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - 命令名或操作名，例如 `GET`
+    fn before(&self, command: &str) -> Result<()> {
+        let _ = command;
+        Ok(())
+    }
+
+    /// 操作执行后调用，只有 `before` 成功的拦截器才会收到
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - 命令名或操作名
+    /// * `result` - 操作结果，成功时为 `Ok(())`
+    fn after(&self, command: &str, result: std::result::Result<(), &ConnectionError>) {
+        let _ = (command, result);
+    }
+}
+
+/// 带拦截器链的连接 - 每个操作都会依次经过已注册的拦截器
+///
+/// `before` 按注册顺序调用，`after` 按相反顺序调用。某个拦截器的 `before`
+/// 返回错误时，操作不会执行，已经执行过 `before` 的拦截器仍会收到 `after`。
+/// 操作在完成前被取消（例如外层超时丢弃了 future）时，拦截器会收到 `ConnectionError::Timeout`。
+#[derive(Clone)]
+pub struct InterceptedConnection {
+    conn: ConnectionManager,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+}
+
+impl InterceptedConnection {
+    /// 使用已有的连接管理器创建连接
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - Redis 连接管理器
+    ///
+    /// # Returns
+    ///
+    /// 返回没有任何拦截器的 InterceptedConnection 实例
+    pub fn new(conn: ConnectionManager) -> Self {
+        Self {
+            conn,
+            interceptors: Vec::new(),
+        }
+    }
+
+    /// 在拦截器链末尾注册拦截器
+    pub fn with(mut self, interceptor: impl Interceptor + 'static) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    /// 获取内部连接管理器的克隆，通过它执行的命令不会经过拦截器
+    pub fn connection_manager(&self) -> ConnectionManager {
+        self.conn.clone()
+    }
+
+    /// 执行任意命令，拦截器收到的命令名为命令的第一个参数
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - 要执行的命令
+    ///
+    /// # Returns
+    ///
+    /// 返回解析后的回复或错误
+    pub async fn query<T: FromRedisValue>(&self, cmd: &Cmd) -> Result<T> {
+        let command = match cmd.args_iter().next() {
+            Some(Arg::Simple(name)) => String::from_utf8_lossy(name).to_uppercase(),
+            _ => String::new(),
+        };
+        self.execute(&command, |mut conn| async move {
+            let result: T = cmd.query_async(&mut conn).await?;
+            Ok(result)
+        })
+        .await
+    }
+
+    /// 让任意操作经过拦截器链，可用于包装 [`crate::RedisUtils`] 的方法
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - 交给拦截器的操作名
+    /// * `f` - 接收连接管理器克隆的操作
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let value: Option<String> = conn
+    ///     .execute("GET", |mut c| async move { RedisUtils::get(&mut c, "key").await })
+    ///     .await?;
+    /// ```
+    pub async fn execute<T, F, Fut>(&self, command: &str, f: F) -> Result<T>
+    where
+        F: FnOnce(ConnectionManager) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        for (entered, interceptor) in self.interceptors.iter().enumerate() {
+            if let Err(e) = interceptor.before(command) {
+                for interceptor in self.interceptors[..entered].iter().rev() {
+                    interceptor.after(command, Err(&e));
+                }
+                return Err(e);
+            }
+        }
+
+        let mut pending = Pending {
+            interceptors: &self.interceptors,
+            command,
+            done: false,
+        };
+        let result = f(self.conn.clone()).await;
+        pending.done = true;
+
+        let outcome = result.as_ref().map(|_| ());
+        for interceptor in self.interceptors.iter().rev() {
+            interceptor.after(command, outcome);
+        }
+        result
+    }
+}
+
+/// 正在执行的操作，操作未完成就被丢弃时以 `ConnectionError::Timeout` 通知所有拦截器，
+/// 保证每次成功的 `before` 都有对应的 `after`
+struct Pending<'a> {
+    interceptors: &'a [Arc<dyn Interceptor>],
+    command: &'a str,
+    done: bool,
+}
+
+impl Drop for Pending<'_> {
+    fn drop(&mut self) {
+        if !self.done {
+            for interceptor in self.interceptors.iter().rev() {
+                interceptor.after(self.command, Err(&ConnectionError::Timeout));
+            }
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::connector::RedisConnector;
+use crate::error::Result;
+use crate::utils::RedisUtils;
+use futures_util::StreamExt;
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+
+/// 键空间通知的事件类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventType {
+    /// SET 等字符串写入
+    Set,
+    /// DEL 删除
+    Del,
+    /// 设置过期时间（EXPIRE 等）
+    Expire,
+    /// 键因过期被删除
+    Expired,
+    /// 键因内存淘汰被删除
+    Evicted,
+    /// RENAME 的源键
+    RenameFrom,
+    /// RENAME 的目标键
+    RenameTo,
+    /// 任意事件，会开启全部通知类别（`A`）
+    Any,
+    /// 其他事件，例如 `hset`、`lpush`
+    ///
+    /// 已知的事件名会自动开启对应类别的通知；无法识别的事件名不会修改服务器配置，
+    /// 需要服务器已开启对应类别的通知。
+    Other(String),
+}
+
+impl EventType {
+    /// 获取事件在通知中的名称，`Any` 返回 None
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            EventType::Set => Some("set"),
+            EventType::Del => Some("del"),
+            EventType::Expire => Some("expire"),
+            EventType::Expired => Some("expired"),
+            EventType::Evicted => Some("evicted"),
+            EventType::RenameFrom => Some("rename_from"),
+            EventType::RenameTo => Some("rename_to"),
+            EventType::Any => None,
+            EventType::Other(name) => Some(name),
+        }
+    }
+
+    /// 获取接收该事件所需的 notify-keyspace-events 标志，无法识别的事件返回 None
+    fn flags(&self) -> Option<&'static str> {
+        match self {
+            EventType::Set => Some("K$"),
+            EventType::Del | EventType::Expire | EventType::RenameFrom | EventType::RenameTo => {
+                Some("Kg")
+            }
+            EventType::Expired => Some("Kx"),
+            EventType::Evicted => Some("Ke"),
+            EventType::Any => Some("KA"),
+            EventType::Other(name) => Self::flags_of(name),
+        }
+    }
+
+    /// 按事件名查找所属通知类别的标志
+    fn flags_of(name: &str) -> Option<&'static str> {
+        let flags = match name {
+            "set" | "setrange" | "incrby" | "incrbyfloat" | "append" => "K$",
+            "del" | "expire" | "rename_from" | "rename_to" | "copy_to" | "move_from"
+            | "move_to" | "restore" | "persist" => "Kg",
+            "expired" => "Kx",
+            "evicted" => "Ke",
+            "new" => "Kn",
+            "lpush" | "rpush" | "lpop" | "rpop" | "linsert" | "lset" | "lrem" | "ltrim" => "Kl",
+            "sadd" | "srem" | "spop" | "sinterstore" | "sunionstore" | "sdiffstore" => "Ks",
+            "hset" | "hdel" | "hincrby" | "hincrbyfloat" | "hexpire" | "hpersist" | "hexpired" => {
+                "Kh"
+            }
+            "zadd" | "zincr" | "zrem" | "zremrangebyscore" | "zremrangebyrank"
+            | "zremrangebylex" | "zinterstore" | "zunionstore" | "zdiffstore" => "Kz",
+            "xadd"
+            | "xdel"
+            | "xtrim"
+            | "xsetid"
+            | "xgroup-create"
+            | "xgroup-createconsumer"
+            | "xgroup-delconsumer"
+            | "xgroup-destroy"
+            | "xgroup-setid" => "Kt",
+            _ => return None,
+        };
+        Some(flags)
+    }
+}
+
+/// 键事件监听器 - 在后台把匹配的键空间通知转交给回调
+///
+/// 通过 [`RedisConnector::on_key_event`] 创建。订阅连接断开后会按
+/// [`crate::PoolConfig::retry_delay`] 退避重连，并重新开启所需的通知类别。
+/// 键空间通知是"发后即忘"的，断线期间发生的事件会丢失。监听器被丢弃时后台任务随之终止。
+pub struct KeyEventListener {
+    task: JoinHandle<()>,
+}
+
+impl KeyEventListener {
+    /// 开启通知、订阅并启动后台任务，首次连接失败时直接返回错误
+    pub(crate) async fn start<F>(
+        connector: RedisConnector,
+        pattern: &str,
+        event: EventType,
+        handler: F,
+    ) -> Result<Self>
+    where
+        F: Fn(String) + Send + Sync + 'static,
+    {
+        let channel_prefix = format!("__keyspace@{}__:", connector.db);
+        let channel_pattern = format!("{channel_prefix}{pattern}");
+
+        let pubsub = Self::connect(&connector, &channel_pattern, &event).await?;
+        let handler = Arc::new(handler);
+
+        let task = tokio::spawn(async move {
+            let mut pubsub = pubsub;
+            loop {
+                let mut stream = pubsub.into_on_message();
+                while let Some(msg) = stream.next().await {
+                    let Ok(name) = msg.get_payload::<String>() else {
+                        continue;
+                    };
+                    if event.name().is_some_and(|expected| expected != name) {
+                        continue;
+                    }
+                    if let Some(key) = msg.get_channel_name().strip_prefix(&channel_prefix) {
+                        handler(key.to_string());
+                    }
+                }
+
+                // 订阅连接已断开，退避后重连
+                let mut attempt = 0;
+                pubsub = loop {
+                    let delay = connector.pool_config.retry_delay(attempt);
+                    log::debug!("Key event subscription lost, reconnecting in {delay:?}");
+                    tokio::time::sleep(delay).await;
+                    match Self::connect(&connector, &channel_pattern, &event).await {
+                        Ok(pubsub) => break pubsub,
+                        Err(e) => {
+                            log::debug!("Failed to resubscribe to key events: {e}");
+                            attempt = attempt.saturating_add(1);
+                        }
+                    }
+                };
+            }
+        });
+
+        Ok(Self { task })
+    }
+
+    /// 开启通知类别并建立订阅连接
+    async fn connect(
+        connector: &RedisConnector,
+        channel_pattern: &str,
+        event: &EventType,
+    ) -> Result<redis::aio::PubSub> {
+        // 托管 Redis 常禁用 CONFIG 命令，此时假定服务器已开启所需的通知类别
+        if let Some(flags) = event.flags() {
+            let mut conn = connector.connection_manager().await?;
+            if let Err(e) = RedisUtils::enable_keyspace_events(&mut conn, flags).await {
+                log::debug!("Failed to enable keyspace events `{flags}`, subscribing anyway: {e}");
+            }
+        }
+
+        let mut pubsub = connector.client()?.get_async_pubsub().await?;
+        pubsub.psubscribe(channel_pattern).await?;
+        Ok(pubsub)
+    }
+}
+
+impl Drop for KeyEventListener {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -1,12 +1,63 @@
+pub mod bitfield;
+pub mod cache;
+pub mod circuit;
 pub mod client;
 pub mod connector;
+pub mod deadline;
+#[cfg(feature = "encryption")]
+pub mod encryption;
 pub mod error;
+pub mod handle;
+pub mod interceptor;
+pub mod keyevent;
+pub mod limit;
+pub mod pipeline;
 pub mod pool;
+pub mod proxy;
+pub mod pubsub;
+pub mod ratio;
+pub mod script;
+pub mod sequence;
+pub mod sharded;
+pub mod slot;
+pub mod stream;
+pub mod types;
 pub mod utils;
+pub mod weighted;
 
+pub use bitfield::{BitField, BitFieldType, Overflow};
+pub use cache::CacheUtils;
+pub use circuit::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
 pub use client::RedisClient;
 pub use connector::RedisConnector;
+pub use deadline::Deadline;
+#[cfg(feature = "encryption")]
+pub use encryption::EncryptionKey;
 pub use error::{ConnectionError, Result};
-pub use pool::{PoolConfig, RedisPool};
+pub use handle::Redis;
+pub use interceptor::{InterceptedConnection, Interceptor};
+pub use keyevent::{EventType, KeyEventListener};
+pub use limit::SizeLimit;
+pub use pipeline::{PipelineResults, PipelineSlot, RedisPipeline};
+pub use pool::{
+    ConnectionPool, PoolConfig, PooledConnection, ReconnectEvent, ReconnectingManager, RedisPool,
+};
+pub use proxy::ProxyConfig;
+pub use pubsub::{
+    ClusterShardedSubscriber, LatestValueSubscriber, OverflowPolicy, RedisSubscriber, Router,
+    ShardedSubscriber, SubscriberConfig,
+};
+pub use ratio::RatioCounter;
 pub use redis::aio::ConnectionManager;
+pub use script::RedisScript;
+pub use sequence::Sequence;
+pub use sharded::ShardedConnector;
+pub use slot::key_slot;
+pub use stream::StreamReader;
+pub use types::{
+    AclUser, Aggregate, BigKey, ClientInfo, ClusterNode, End, GroupInfo, KeyInfo, LatencyEvent,
+    LcsIdx, LcsMatch, MemoryReport, PauseMode, SetCondition, SetOptions, SetResponse, SetTtl,
+    StreamInfo, Version, ZAddComparison, ZAddCondition, ZAddOptions,
+};
 pub use utils::RedisUtils;
+pub use weighted::WeightedPool;
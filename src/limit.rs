@@ -0,0 +1,146 @@
+use crate::error::{ConnectionError, Result};
+use crate::utils::RedisUtils;
+use redis::FromRedisValue;
+use redis::aio::ConnectionManager;
+use std::collections::HashMap;
+
+/// 回复大小守卫 - 在读取集合前检查其大小，拒绝可能耗尽内存的超大回复
+///
+/// 通过 [`RedisUtils::with_size_limit`] 创建。每次读取前先以 LLEN/SCARD/HLEN/ZCARD/STRLEN
+/// 获取元素数量或字节数，超过上限时返回 `ConnectionError::Configuration("response too large")`
+/// 而不会真正拉取数据；读取整个集合时字节数以 MEMORY USAGE 估算。检查与读取之间集合仍可能增长，
+/// 因此上限应留有余量。
+///
+/// 范围读取（[`SizeLimit::lrange`]、[`SizeLimit::zrange`]）只检查返回的元素数量，不检查字节上限：
+/// MEMORY USAGE 只能反映整个集合的大小，无法换算出某个范围的回复大小。
+pub struct SizeLimit<'a> {
+    conn: &'a mut ConnectionManager,
+    max_elements: Option<u64>,
+    max_bytes: Option<u64>,
+}
+
+impl<'a> SizeLimit<'a> {
+    /// 创建回复大小守卫
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - Redis 连接管理器
+    /// * `max_elements` - 集合回复的最大元素数量，为 None 时不限制
+    /// * `max_bytes` - 回复的最大字节数，为 None 时不限制；范围读取不检查此项
+    ///
+    /// # Returns
+    ///
+    /// 返回 SizeLimit 实例
+    pub fn new(
+        conn: &'a mut ConnectionManager,
+        max_elements: Option<u64>,
+        max_bytes: Option<u64>,
+    ) -> Self {
+        Self {
+            conn,
+            max_elements,
+            max_bytes,
+        }
+    }
+
+    /// 在大小限制内获取字符串值，参见 [`RedisUtils::get`]
+    pub async fn get<V>(self, key: &str) -> Result<Option<V>>
+    where
+        V: FromRedisValue,
+    {
+        if let Some(max_bytes) = self.max_bytes {
+            let len: u64 = redis::cmd("STRLEN").arg(key).query_async(self.conn).await?;
+            Self::check("bytes", len, max_bytes)?;
+        }
+        RedisUtils::get(self.conn, key).await
+    }
+
+    /// 在大小限制内获取列表指定范围的元素，参见 [`RedisUtils::lrange`]
+    pub async fn lrange<V>(mut self, key: &str, start: isize, stop: isize) -> Result<Vec<V>>
+    where
+        V: FromRedisValue,
+    {
+        let len = self.collection_len("LLEN", key).await?;
+        self.check_elements(Self::range_len(len, start, stop))?;
+        RedisUtils::lrange(self.conn, key, start, stop).await
+    }
+
+    /// 在大小限制内获取集合的所有成员，参见 [`RedisUtils::smembers`]
+    pub async fn smembers<V>(mut self, key: &str) -> Result<Vec<V>>
+    where
+        V: FromRedisValue,
+    {
+        let len = self.collection_len("SCARD", key).await?;
+        self.check_elements(len)?;
+        self.check_memory(key).await?;
+        RedisUtils::smembers(self.conn, key).await
+    }
+
+    /// 在大小限制内获取哈希表所有字段和值，参见 [`RedisUtils::hgetall`]
+    pub async fn hgetall(mut self, key: &str) -> Result<HashMap<String, String>> {
+        let len = self.collection_len("HLEN", key).await?;
+        self.check_elements(len)?;
+        self.check_memory(key).await?;
+        RedisUtils::hgetall(self.conn, key).await
+    }
+
+    /// 在大小限制内获取有序集合指定范围的成员，参见 [`RedisUtils::zrange`]
+    pub async fn zrange<V>(mut self, key: &str, start: isize, stop: isize) -> Result<Vec<V>>
+    where
+        V: FromRedisValue,
+    {
+        let len = self.collection_len("ZCARD", key).await?;
+        self.check_elements(Self::range_len(len, start, stop))?;
+        RedisUtils::zrange(self.conn, key, start, stop).await
+    }
+
+    /// 获取集合的元素数量
+    async fn collection_len(&mut self, command: &str, key: &str) -> Result<u64> {
+        Ok(redis::cmd(command).arg(key).query_async(self.conn).await?)
+    }
+
+    /// 设置了字节上限时以 MEMORY USAGE 检查整个集合的大小
+    async fn check_memory(&mut self, key: &str) -> Result<()> {
+        if let Some(max_bytes) = self.max_bytes {
+            let bytes: Option<u64> = redis::cmd("MEMORY")
+                .arg("USAGE")
+                .arg(key)
+                .query_async(self.conn)
+                .await?;
+            Self::check("bytes", bytes.unwrap_or(0), max_bytes)?;
+        }
+        Ok(())
+    }
+
+    fn check_elements(&self, count: u64) -> Result<()> {
+        match self.max_elements {
+            Some(max_elements) => Self::check("elements", count, max_elements),
+            None => Ok(()),
+        }
+    }
+
+    fn check(unit: &str, actual: u64, limit: u64) -> Result<()> {
+        if actual > limit {
+            return Err(ConnectionError::Configuration(format!(
+                "response too large: {actual} {unit} exceeds the limit of {limit}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// 按 Redis 的下标规则计算范围查询实际返回的元素数量
+    fn range_len(len: u64, start: isize, stop: isize) -> u64 {
+        let len = len as i64;
+        let normalize = |index: isize| {
+            let index = index as i64;
+            if index < 0 { len + index } else { index }
+        };
+        let start = normalize(start).max(0);
+        let stop = normalize(stop).min(len - 1);
+        if start > stop {
+            0
+        } else {
+            (stop - start + 1) as u64
+        }
+    }
+}
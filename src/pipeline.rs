@@ -0,0 +1,210 @@
+use crate::error::{ConnectionError, Result};
+use redis::aio::ConnectionManager;
+use redis::{Cmd, FromRedisValue, ToRedisArgs, Value};
+use std::marker::PhantomData;
+
+/// 流水线中某条命令的回复位置，携带该回复的目标类型
+#[derive(Debug)]
+pub struct PipelineSlot<T> {
+    index: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> PipelineSlot<T> {
+    /// 获取该命令在流水线中的序号
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<T> Clone for PipelineSlot<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for PipelineSlot<T> {}
+
+/// 带类型回复的 Redis 流水线 - 在一次往返中执行多条命令
+///
+/// 每条入队的命令都会返回一个 [`PipelineSlot`]，执行后通过
+/// [`PipelineResults::take`] 按入队时指定的类型解析对应回复。
+#[derive(Default)]
+pub struct RedisPipeline {
+    pipe: redis::Pipeline,
+    len: usize,
+}
+
+impl RedisPipeline {
+    /// 创建新的流水线
+    ///
+    /// # Returns
+    ///
+    /// 返回空的 RedisPipeline 实例
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 以 MULTI/EXEC 事务的方式执行流水线
+    pub fn atomic(mut self) -> Self {
+        self.pipe.atomic();
+        self
+    }
+
+    /// 添加任意命令
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - 要执行的命令
+    ///
+    /// # Returns
+    ///
+    /// 返回该命令回复的位置
+    pub fn add<T: FromRedisValue>(&mut self, cmd: Cmd) -> PipelineSlot<T> {
+        self.pipe.add_command(cmd);
+        let index = self.len;
+        self.len += 1;
+        PipelineSlot {
+            index,
+            _marker: PhantomData,
+        }
+    }
+
+    /// 添加 GET 命令
+    pub fn get<K, V>(&mut self, key: K) -> PipelineSlot<Option<V>>
+    where
+        K: ToRedisArgs,
+        V: FromRedisValue,
+    {
+        self.add(Cmd::get(key))
+    }
+
+    /// 添加 SET 命令
+    pub fn set<K, V>(&mut self, key: K, value: V) -> PipelineSlot<()>
+    where
+        K: ToRedisArgs,
+        V: ToRedisArgs,
+    {
+        self.add(Cmd::set(key, value))
+    }
+
+    /// 添加 HGETALL 命令
+    pub fn hgetall<K, V>(&mut self, key: K) -> PipelineSlot<V>
+    where
+        K: ToRedisArgs,
+        V: FromRedisValue,
+    {
+        self.add(Cmd::hgetall(key))
+    }
+
+    /// 添加 LRANGE 命令
+    pub fn lrange<K, V>(&mut self, key: K, start: isize, stop: isize) -> PipelineSlot<Vec<V>>
+    where
+        K: ToRedisArgs,
+        V: FromRedisValue,
+    {
+        self.add(Cmd::lrange(key, start, stop))
+    }
+
+    /// 添加 SMEMBERS 命令
+    pub fn smembers<K, V>(&mut self, key: K) -> PipelineSlot<Vec<V>>
+    where
+        K: ToRedisArgs,
+        V: FromRedisValue,
+    {
+        self.add(Cmd::smembers(key))
+    }
+
+    /// 添加 ZRANGE 命令
+    pub fn zrange<K, V>(&mut self, key: K, start: isize, stop: isize) -> PipelineSlot<Vec<V>>
+    where
+        K: ToRedisArgs,
+        V: FromRedisValue,
+    {
+        self.add(Cmd::zrange(key, start, stop))
+    }
+
+    /// 获取已入队的命令数量
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 判断流水线是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 执行流水线
+    ///
+    /// # Returns
+    ///
+    /// 返回所有命令的原始回复，可按位置解析为具体类型
+    pub async fn execute(self, conn: &mut ConnectionManager) -> Result<PipelineResults> {
+        if self.len == 0 {
+            return Ok(PipelineResults { values: Vec::new() });
+        }
+
+        let values: Vec<Value> = self.pipe.query_async(conn).await?;
+        Ok(PipelineResults { values })
+    }
+}
+
+/// 流水线执行结果
+#[derive(Debug, Clone)]
+pub struct PipelineResults {
+    values: Vec<Value>,
+}
+
+impl PipelineResults {
+    /// 按入队时的位置解析回复
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - 入队时返回的回复位置
+    ///
+    /// # Returns
+    ///
+    /// 返回解析后的值或错误
+    pub fn take<T: FromRedisValue>(&self, slot: PipelineSlot<T>) -> Result<T> {
+        self.get(slot.index)
+    }
+
+    /// 将第 `index` 条回复解析为指定类型
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - 命令在流水线中的序号
+    ///
+    /// # Returns
+    ///
+    /// 返回解析后的值，序号越界或类型不匹配时返回带有序号的错误
+    pub fn get<T: FromRedisValue>(&self, index: usize) -> Result<T> {
+        let value = self.values.get(index).ok_or_else(|| {
+            ConnectionError::Deserialization(format!(
+                "Pipeline reply #{index} out of range ({} replies)",
+                self.values.len()
+            ))
+        })?;
+
+        T::from_redis_value(value).map_err(|e| {
+            ConnectionError::Deserialization(format!(
+                "Failed to decode pipeline reply #{index}: {e}"
+            ))
+        })
+    }
+
+    /// 获取回复数量
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// 判断是否没有任何回复
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// 获取所有原始回复
+    pub fn into_values(self) -> Vec<Value> {
+        self.values
+    }
+}
@@ -1,8 +1,16 @@
 use crate::ConnectionError;
 use crate::client::{RedisClient, RedisConfig};
+use crate::connector::RedisConnector;
 use crate::error::Result;
-use redis::aio::ConnectionManager;
+use redis::aio::{ConnectionManager, ConnectionManagerConfig};
+use redis::io::tcp::TcpSettings;
+use redis::io::tcp::socket2::TcpKeepalive;
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
 use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 
 /// Redis 连接池配置
 #[derive(Debug, Clone)]
@@ -15,6 +23,14 @@ pub struct PoolConfig {
     pub max_retries: u32,
     /// 保持连接活跃
     pub keep_alive: bool,
+    /// 重试间隔的随机抖动比例（0.0 ~ 1.0），每次等待时间会在
+    /// `[delay * (1 - jitter_fraction), delay]` 范围内随机选取，1.0 即完全抖动，NaN 等非有限值按 0 处理
+    pub jitter_fraction: f64,
+    /// [`crate::RedisConnector::connection_pool`] 建立的连接数量，为 None 时等于可用的 CPU 并行度
+    pub pool_size: Option<usize>,
+    /// 连接池中可通过 [`ConnectionPool::checkout`] 独占借出的连接数量，
+    /// 这些连接独立于 `pool_size`，不参与 [`ConnectionPool::get`] 的轮询
+    pub dedicated_size: usize,
 }
 
 impl Default for PoolConfig {
@@ -24,10 +40,45 @@ impl Default for PoolConfig {
             retry_interval: Duration::from_millis(100),
             max_retries: 3,
             keep_alive: true,
+            jitter_fraction: 1.0,
+            pool_size: None,
+            dedicated_size: 0,
         }
     }
 }
 
+impl PoolConfig {
+    /// 重试等待时间的上限
+    const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+    /// 计算第 `attempt` 次重试前的等待时间（从 0 开始）
+    ///
+    /// 以 `retry_interval` 为基数按指数增长，并按 `jitter_fraction` 随机缩短，
+    /// 避免大量实例在 Redis 重启后同时重连。
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - 已重试的次数
+    ///
+    /// # Returns
+    ///
+    /// 返回本次重试前应等待的时间
+    pub fn retry_delay(&self, attempt: u32) -> Duration {
+        let delay = self
+            .retry_interval
+            .saturating_mul(1 << attempt.min(16))
+            .min(Self::MAX_RETRY_DELAY);
+        // NaN 或无穷大的抖动比例按不抖动处理，避免 mul_f64 因非有限值而 panic
+        let fraction = if self.jitter_fraction.is_finite() {
+            self.jitter_fraction.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let jitter = fraction * fastrand::f64();
+        delay.mul_f64(1.0 - jitter)
+    }
+}
+
 /// Redis 连接池 - 使用 redis connection-manager
 pub struct RedisPool;
 
@@ -43,18 +94,601 @@ impl RedisPool {
     ///
     /// 返回 ConnectionManager 实例或错误
     pub async fn create(config: RedisConfig) -> Result<ConnectionManager> {
-        // 构建 Redis URL
-        let redis_url = RedisClient::build_redis_url(&config)?;
+        // 创建 Redis 客户端，启用 TLS 时会加载配置的证书，配置了代理时返回配置错误
+        let client = RedisClient::open(&config).map_err(|e| match e {
+            ConnectionError::ClientCreation(e) => {
+                ConnectionError::PoolCreation(format!("Failed to create client: {e}"))
+            }
+            other => other,
+        })?;
 
-        // 创建 Redis 客户端
-        let client = redis::Client::open(redis_url)
-            .map_err(|e| ConnectionError::PoolCreation(format!("Failed to create client: {e}")))?;
+        // 创建连接管理器，TCP 选项会应用到每次（重）连接建立的套接字上
+        let mut tcp_settings = TcpSettings::default().set_nodelay(config.tcp_nodelay);
+        if let Some(idle) = config.tcp_keepalive {
+            tcp_settings = tcp_settings.set_keepalive(TcpKeepalive::new().with_time(idle));
+        }
+        let manager_config = ConnectionManagerConfig::new().set_tcp_settings(tcp_settings);
+        let mut manager = ConnectionManager::new_with_config(client, manager_config)
+            .await
+            .map_err(|e| {
+                ConnectionError::PoolCreation(format!("Failed to create connection manager: {e}"))
+            })?;
 
-        // 创建连接管理器
-        let manager = ConnectionManager::new(client).await.map_err(|e| {
-            ConnectionError::PoolCreation(format!("Failed to create connection manager: {e}"))
-        })?;
+        // 副本节点需要 READONLY 才会处理读请求。注意 ConnectionManager 在后台自动重连后
+        // 不会重新发送 READONLY，需要该保证时请使用 ReconnectingManager，它会在重连后重新发送
+        if config.readonly {
+            let _: () = redis::cmd("READONLY")
+                .query_async(&mut manager)
+                .await
+                .map_err(|e| {
+                    ConnectionError::PoolCreation(format!("Failed to enable READONLY: {e}"))
+                })?;
+        }
 
         Ok(manager)
     }
+
+    /// 回收出错后的连接，使其可以安全地交给下一个调用方
+    ///
+    /// 先发送 DISCARD 退出可能残留的 MULTI 事务（不在事务中时的错误会被忽略），
+    /// 再发送 UNWATCH 取消 MULTI 之外残留的 WATCH，最后通过 PING 确认连接健康。这里不使用 RESET：它会同时清除认证状态、
+    /// 切回 0 号数据库并取消 READONLY，而 `ConnectionManager` 不会重新执行这些初始化。
+    ///
+    /// 只能用于调用方独占的连接，例如 [`ConnectionPool::checkout`] 借出的连接。
+    /// 多路复用的连接被多个任务共享时，DISCARD 会中止其他任务正在进行的 MULTI 事务。
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - 要回收的连接管理器
+    ///
+    /// # Returns
+    ///
+    /// 连接健康时返回 Ok，否则返回错误，调用方应丢弃并重建该连接
+    pub async fn recycle(conn: &mut ConnectionManager) -> Result<()> {
+        let discarded: redis::RedisResult<()> = redis::cmd("DISCARD").query_async(conn).await;
+        if let Err(e) = discarded {
+            // 不在事务中时 DISCARD 返回 "DISCARD without MULTI"，属于正常情况
+            if e.kind() != redis::ErrorKind::ResponseError {
+                return Err(e.into());
+            }
+        }
+        let _: () = redis::cmd("UNWATCH").query_async(conn).await?;
+
+        let pong: String = redis::cmd("PING").query_async(conn).await?;
+        if pong != "PONG" {
+            return Err(ConnectionError::Network(format!(
+                "Unexpected PING reply while recycling connection: {pong}"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// 轮询分发的连接管理器组
+///
+/// 单个 `ConnectionManager` 的所有回复都在同一个套接字上解复用，高并发时会成为瓶颈。
+/// 本类型持有多个独立建立的连接管理器（各自拥有独立的套接字），
+/// 通过 [`ConnectionPool::get`] 以轮询方式分发，把负载分散到多条连接上。
+///
+/// 需要独占某条连接时（例如 WATCH/MULTI 事务），使用 [`ConnectionPool::checkout`]
+/// 借出连接，借出的连接在守卫被丢弃时自动归还。可借出的连接是单独建立的一组连接
+/// （参见 `PoolConfig::dedicated_size`），[`ConnectionPool::get`] 永远不会分发它们。
+/// 归还的连接要先经过 [`RedisPool::recycle`] 清除残留的事务与 WATCH 状态，才会再次借出；
+/// 通过 [`ConnectionPool::with_connector`] 设置连接器后，回收失败的连接会被新建立的连接替换。
+#[derive(Clone)]
+pub struct ConnectionPool {
+    managers: Arc<[ConnectionManager]>,
+    next: Arc<AtomicUsize>,
+    idle: Arc<Mutex<Vec<IdleConnection>>>,
+    permits: Arc<Semaphore>,
+    dedicated: usize,
+    connector: Option<Arc<RedisConnector>>,
+}
+
+impl ConnectionPool {
+    /// 默认的连接数量，等于可用的 CPU 并行度
+    pub fn default_size() -> usize {
+        std::thread::available_parallelism().map_or(1, |n| n.get())
+    }
+
+    /// 使用已建立的连接管理器创建连接组
+    ///
+    /// # Arguments
+    ///
+    /// * `managers` - 连接管理器列表，不能为空
+    ///
+    /// # Returns
+    ///
+    /// 返回 ConnectionPool 实例或错误
+    pub fn new(managers: Vec<ConnectionManager>) -> Result<Self> {
+        Self::with_dedicated(managers, Vec::new())
+    }
+
+    /// 使用已建立的连接管理器创建连接组，并附带一组可独占借出的连接
+    ///
+    /// # Arguments
+    ///
+    /// * `managers` - 轮询分发的连接管理器列表，不能为空
+    /// * `dedicated` - 只能通过 [`ConnectionPool::checkout`] 借出的连接管理器列表，
+    ///   必须与 `managers` 使用各自独立的连接
+    ///
+    /// # Returns
+    ///
+    /// 返回 ConnectionPool 实例或错误
+    pub fn with_dedicated(
+        managers: Vec<ConnectionManager>,
+        dedicated: Vec<ConnectionManager>,
+    ) -> Result<Self> {
+        if managers.is_empty() {
+            return Err(ConnectionError::Configuration(
+                "Connection pool size must be greater than 0".to_string(),
+            ));
+        }
+
+        let idle = dedicated
+            .into_iter()
+            .map(|conn| IdleConnection { conn, dirty: false })
+            .collect::<Vec<_>>();
+        Ok(Self {
+            permits: Arc::new(Semaphore::new(idle.len())),
+            dedicated: idle.len(),
+            idle: Arc::new(Mutex::new(idle)),
+            managers: managers.into(),
+            next: Arc::new(AtomicUsize::new(0)),
+            connector: None,
+        })
+    }
+
+    /// 设置用于替换损坏连接的连接器
+    ///
+    /// 设置后，回收可借出的连接时还会切回连接器配置的数据库并按需重新发送 READONLY，
+    /// 回收失败的连接会丢弃并通过连接器重新建立。
+    /// [`crate::RedisConnector::connection_pool`] 创建的连接组已经设置了连接器。
+    ///
+    /// # Arguments
+    ///
+    /// * `connector` - 创建这些连接的连接器
+    ///
+    /// # Returns
+    ///
+    /// 返回设置了连接器的连接组
+    pub fn with_connector(mut self, connector: RedisConnector) -> Self {
+        self.connector = Some(Arc::new(connector));
+        self
+    }
+
+    /// 以轮询方式获取一个连接管理器
+    pub fn get(&self) -> ConnectionManager {
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % self.managers.len();
+        self.managers[index].clone()
+    }
+
+    /// 独占借出一条连接，所有连接都已借出时等待归还
+    ///
+    /// 借出的连接来自独立的一组连接，借出期间不会被其他任何调用方使用，
+    /// 可以安全地执行 WATCH/MULTI 事务或 [`RedisPool::recycle`]。
+    /// 取到的连接尚未完成回收时，会先回收（必要时替换）再借出。
+    ///
+    /// # Returns
+    ///
+    /// 返回借出的连接守卫，守卫被丢弃时连接自动归还；
+    /// 连接池没有可借出的连接（`dedicated_size` 为 0）时返回配置错误，
+    /// 连接回收失败且无法替换时返回对应的错误，连接留在池中等待下次回收
+    pub async fn checkout(&self) -> Result<PooledConnection> {
+        if self.dedicated == 0 {
+            return Err(ConnectionError::Configuration(
+                "Connection pool has no dedicated connections, set PoolConfig::dedicated_size"
+                    .to_string(),
+            ));
+        }
+        let permit =
+            self.permits.clone().acquire_owned().await.map_err(|e| {
+                ConnectionError::PoolCreation(format!("Connection pool closed: {e}"))
+            })?;
+
+        // 回收期间 future 可能被丢弃，由 Slot 负责把连接放回空闲列表
+        let mut slot = self.take_idle(permit, |_| true);
+        let entry = slot.entry();
+        if entry.dirty {
+            Self::restore(&mut entry.conn, self.connector.as_deref()).await?;
+            entry.dirty = false;
+        }
+        Ok(PooledConnection {
+            slot: Some(slot),
+            connector: self.connector.clone(),
+        })
+    }
+
+    /// 尝试立即借出一条连接
+    ///
+    /// # Returns
+    ///
+    /// 返回借出的连接守卫，所有连接都已借出、连接仍在回收或没有可借出的连接时返回 None
+    pub fn try_checkout(&self) -> Option<PooledConnection> {
+        let permit = self.permits.clone().try_acquire_owned().ok()?;
+        let slot = self.take_idle(permit, |entry| !entry.dirty);
+        let found = slot.entry.is_some();
+        found.then(|| PooledConnection {
+            slot: Some(slot),
+            connector: self.connector.clone(),
+        })
+    }
+
+    /// 获取当前未借出的连接数量
+    pub fn available(&self) -> usize {
+        self.permits.available_permits()
+    }
+
+    /// 获取轮询分发的连接数量
+    pub fn size(&self) -> usize {
+        self.managers.len()
+    }
+
+    /// 获取可独占借出的连接数量
+    pub fn dedicated_size(&self) -> usize {
+        self.dedicated
+    }
+
+    /// 回收借出过的连接，使其可以安全地再次借出
+    ///
+    /// 有连接器时，回收成功后切回连接器配置的数据库并按需重新发送 READONLY；
+    /// 回收失败时丢弃该连接，改用连接器新建立的连接，不会把损坏的连接再次借出。
+    async fn restore(
+        conn: &mut ConnectionManager,
+        connector: Option<&RedisConnector>,
+    ) -> Result<()> {
+        let recycled = RedisPool::recycle(conn).await;
+        let Some(connector) = connector else {
+            return recycled;
+        };
+
+        if let Err(e) = recycled {
+            log::debug!("Replacing pooled connection that failed recycling: {e}");
+            *conn = connector.connection_manager().await?;
+            return Ok(());
+        }
+
+        // 借出期间可能执行过 SELECT 或 READWRITE，恢复连接器配置的状态
+        let _: () = redis::cmd("SELECT")
+            .arg(connector.db)
+            .query_async(conn)
+            .await?;
+        if connector.readonly {
+            let _: () = redis::cmd("READONLY").query_async(conn).await?;
+        }
+        Ok(())
+    }
+
+    /// 持有许可后从空闲列表中取出第一条满足 `accept` 的连接，没有时 Slot 为空
+    fn take_idle(
+        &self,
+        permit: OwnedSemaphorePermit,
+        accept: impl Fn(&IdleConnection) -> bool,
+    ) -> Slot {
+        // 许可数量与空闲连接数量一致，持有许可时空闲列表一定不为空
+        let mut idle = self.idle.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = idle
+            .iter()
+            .rposition(accept)
+            .map(|index| idle.swap_remove(index));
+        Slot {
+            entry,
+            idle: self.idle.clone(),
+            _permit: permit,
+        }
+    }
+}
+
+/// 空闲列表中的连接，`dirty` 为 true 时需要先回收才能借出
+struct IdleConnection {
+    conn: ConnectionManager,
+    dirty: bool,
+}
+
+/// 借出中的连接占用的位置，丢弃时先把连接放回空闲列表，再释放许可，保证等待者总能取到连接
+struct Slot {
+    entry: Option<IdleConnection>,
+    idle: Arc<Mutex<Vec<IdleConnection>>>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl Slot {
+    fn entry(&mut self) -> &mut IdleConnection {
+        self.entry
+            .as_mut()
+            .expect("idle connection available while holding a permit")
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        if let Some(entry) = self.entry.take() {
+            self.idle
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(entry);
+        }
+    }
+}
+
+/// 从 [`ConnectionPool`] 借出的连接，丢弃时自动归还到连接池
+///
+/// 实现了 `Deref`/`DerefMut`，可以直接传给 `RedisUtils` 的方法：
+/// `RedisUtils::set(&mut conn, "key", "value").await?`。
+///
+/// 调用方可能在 MULTI 或 WATCH 中途通过 `?` 提前返回，因此归还的连接总是先标记为待回收，
+/// 在后台任务中回收（失败时替换）后才能再次借出；不在 Tokio 运行时中丢弃时，
+/// 回收推迟到下一次 [`ConnectionPool::checkout`]。
+pub struct PooledConnection {
+    slot: Option<Slot>,
+    connector: Option<Arc<RedisConnector>>,
+}
+
+impl Deref for PooledConnection {
+    type Target = ConnectionManager;
+
+    fn deref(&self) -> &Self::Target {
+        let slot = self.slot.as_ref().expect("connection present until drop");
+        &slot
+            .entry
+            .as_ref()
+            .expect("connection present until drop")
+            .conn
+    }
+}
+
+impl DerefMut for PooledConnection {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        let slot = self.slot.as_mut().expect("connection present until drop");
+        &mut slot.entry().conn
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        let Some(mut slot) = self.slot.take() else {
+            return;
+        };
+        slot.entry().dirty = true;
+
+        // 回收任务被取消时 Slot 仍会把连接以待回收状态放回空闲列表
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            let connector = self.connector.clone();
+            handle.spawn(async move {
+                let entry = slot.entry();
+                match ConnectionPool::restore(&mut entry.conn, connector.as_deref()).await {
+                    Ok(()) => entry.dirty = false,
+                    Err(e) => log::debug!("Failed to recycle pooled connection: {e}"),
+                }
+            });
+        }
+    }
+}
+
+/// 连接状态变化事件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReconnectEvent {
+    /// 命令因连接问题失败，`ConnectionManager` 会在后台尝试重连
+    ConnectionLost {
+        /// 当前连续失败次数
+        consecutive_failures: u32,
+    },
+    /// 连续失败后命令重新执行成功
+    Recovered {
+        /// 恢复前的连续失败次数
+        after_failures: u32,
+    },
+    /// 开始通过连接器重建连接管理器
+    RebuildStarted,
+    /// 连接管理器重建成功
+    RebuildSucceeded,
+    /// 连接管理器重建失败
+    RebuildFailed(String),
+}
+
+/// 连接状态变化回调
+type ReconnectHook = Arc<dyn Fn(ReconnectEvent) + Send + Sync>;
+
+/// 可重建的连接管理器
+///
+/// `ConnectionManager` 断线后只会重连到最初解析得到的地址。当连续失败次数达到
+/// `PoolConfig::max_retries` 时，本类型会通过原始的 [`RedisConnector`] 重新创建
+/// 连接管理器（重新解析 DNS），以便在故障转移后连接到新的主节点。
+///
+/// 连接器开启了 `readonly` 时，`ConnectionManager` 在后台重连后不会重新发送 READONLY。
+/// 本类型在出现连接错误或 MOVED 重定向后，会在下一次 [`ReconnectingManager::execute`]
+/// 执行操作前重新发送 READONLY。
+#[derive(Clone)]
+pub struct ReconnectingManager {
+    inner: Arc<ReconnectingInner>,
+}
+
+struct ReconnectingInner {
+    connector: RedisConnector,
+    manager: Mutex<ConnectionManager>,
+    failures: AtomicU32,
+    rebuilding: AtomicBool,
+    readonly_lost: AtomicBool,
+    hook: RwLock<Option<ReconnectHook>>,
+}
+
+impl ReconnectingManager {
+    /// 创建可重建的连接管理器
+    ///
+    /// # Arguments
+    ///
+    /// * `connector` - 用于创建及重建连接的连接器
+    ///
+    /// # Returns
+    ///
+    /// 返回 ReconnectingManager 实例或错误
+    pub async fn new(connector: RedisConnector) -> Result<Self> {
+        let manager = connector.connection_manager().await?;
+
+        Ok(Self {
+            inner: Arc::new(ReconnectingInner {
+                connector,
+                manager: Mutex::new(manager),
+                failures: AtomicU32::new(0),
+                rebuilding: AtomicBool::new(false),
+                readonly_lost: AtomicBool::new(false),
+                hook: RwLock::new(None),
+            }),
+        })
+    }
+
+    /// 获取当前连接管理器的克隆
+    pub fn manager(&self) -> ConnectionManager {
+        self.inner
+            .manager
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// 使用当前连接管理器执行操作，并根据结果记录成功或失败
+    ///
+    /// 连接错误计入连续失败次数，Redis 返回的其他错误不影响连接状态。
+    /// 内部连接管理器由所有调用方共享，因此出错后不会执行 [`RedisPool::recycle`]。
+    ///
+    /// # Arguments
+    ///
+    /// * `op` - 接收连接管理器克隆的异步操作
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn execute<T, F, Fut>(&self, op: F) -> Result<T>
+    where
+        F: FnOnce(ConnectionManager) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut manager = self.manager();
+        if let Err(e) = self.restore_readonly(&mut manager).await {
+            if e.is_connection_error() {
+                self.report_failure().await;
+            }
+            return Err(e);
+        }
+
+        let result = op(manager).await;
+
+        match &result {
+            Ok(_) => self.report_success(),
+            Err(e) if e.is_connection_error() => self.report_failure().await,
+            // 副本连接丢失 READONLY 后，读请求会被重定向到主节点
+            Err(ConnectionError::ClientCreation(e))
+                if self.inner.connector.readonly && e.kind() == redis::ErrorKind::Moved =>
+            {
+                self.inner.readonly_lost.store(true, Ordering::Release);
+            }
+            Err(_) => {}
+        }
+
+        result
+    }
+
+    /// 连接可能已在后台重连时重新发送 READONLY，发送成功后才清除标记
+    async fn restore_readonly(&self, manager: &mut ConnectionManager) -> Result<()> {
+        if !self.inner.readonly_lost.load(Ordering::Acquire) {
+            return Ok(());
+        }
+
+        let _: () = redis::cmd("READONLY").query_async(manager).await?;
+        self.inner.readonly_lost.store(false, Ordering::Release);
+        Ok(())
+    }
+
+    /// 设置连接状态变化回调，用于记录或统计断线、恢复及重建事件
+    ///
+    /// 回调在执行命令的任务中同步调用，应尽量轻量。
+    pub fn set_reconnect_hook<F>(&self, hook: F)
+    where
+        F: Fn(ReconnectEvent) + Send + Sync + 'static,
+    {
+        *self.inner.hook.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(hook));
+    }
+
+    /// 触发连接状态变化回调
+    fn emit(&self, event: ReconnectEvent) {
+        let hook = self
+            .inner
+            .hook
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        if let Some(hook) = hook {
+            hook(event);
+        }
+    }
+
+    /// 记录一次成功，清零连续失败次数
+    pub fn report_success(&self) {
+        let failures = self.inner.failures.swap(0, Ordering::Relaxed);
+        if failures > 0 {
+            self.emit(ReconnectEvent::Recovered {
+                after_failures: failures,
+            });
+        }
+    }
+
+    /// 记录一次连接失败，连续失败次数达到上限时重建连接管理器
+    pub async fn report_failure(&self) {
+        let failures = self.inner.failures.fetch_add(1, Ordering::Relaxed) + 1;
+        if self.inner.connector.readonly {
+            // ConnectionManager 会在后台重连，新连接上没有 READONLY
+            self.inner.readonly_lost.store(true, Ordering::Release);
+        }
+        self.emit(ReconnectEvent::ConnectionLost {
+            consecutive_failures: failures,
+        });
+        if failures < self.inner.connector.pool_config.max_retries {
+            return;
+        }
+
+        log::debug!("Rebuilding Redis connection manager after {failures} consecutive failures");
+        self.rebuild().await;
+    }
+
+    /// 通过连接器重建连接管理器
+    async fn rebuild(&self) {
+        // 同一时间只允许一个任务执行重建
+        if self
+            .inner
+            .rebuilding
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return;
+        }
+        // 调用方的 future 可能在任意 await 处被丢弃，由守卫负责清除标志
+        let _guard = RebuildGuard(&self.inner.rebuilding);
+
+        self.emit(ReconnectEvent::RebuildStarted);
+
+        // 随机等待一段时间，避免所有实例在同一时刻重建连接
+        tokio::time::sleep(self.inner.connector.pool_config.retry_delay(0)).await;
+
+        match self.inner.connector.connection_manager_with_retry().await {
+            Ok(manager) => {
+                *self.inner.manager.lock().unwrap_or_else(|e| e.into_inner()) = manager;
+                self.inner.failures.store(0, Ordering::Relaxed);
+                self.emit(ReconnectEvent::RebuildSucceeded);
+            }
+            Err(e) => {
+                log::debug!("Failed to rebuild Redis connection manager: {e}");
+                self.emit(ReconnectEvent::RebuildFailed(e.to_string()));
+            }
+        }
+    }
+}
+
+/// 重建标志守卫，丢弃时清除标志，使下一次重建可以执行
+struct RebuildGuard<'a>(&'a AtomicBool);
+
+impl Drop for RebuildGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
 }
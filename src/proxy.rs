@@ -0,0 +1,177 @@
+use crate::error::{ConnectionError, Result};
+use std::net::SocketAddr;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+/// SOCKS5 代理配置
+///
+/// 配置代理后只能通过 [`crate::RedisClient::proxied_connection`] 获取连接，该连接仅支持直接执行
+/// `redis::cmd` 命令；其他需要 `redis::Client` 或 `ConnectionManager` 的入口会返回配置错误，
+/// 而不会绕过代理直接连接。
+#[derive(Debug, Clone)]
+pub struct ProxyConfig {
+    /// 代理服务器地址，例如 `bastion.internal:1080`
+    pub addr: String,
+    /// 用户名/密码认证（RFC 1929），为 None 时不认证
+    pub credentials: Option<(String, String)>,
+}
+
+impl ProxyConfig {
+    /// 创建不需要认证的代理配置
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - 代理服务器地址
+    ///
+    /// # Returns
+    ///
+    /// 返回 ProxyConfig 实例
+    pub fn new(addr: impl Into<String>) -> Self {
+        Self {
+            addr: addr.into(),
+            credentials: None,
+        }
+    }
+
+    /// 设置用户名和密码认证
+    pub fn credentials(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.credentials = Some((username.into(), password.into()));
+        self
+    }
+}
+
+/// 目标地址，主机名交给代理解析，使只有代理能解析的内网域名也可以访问
+pub(crate) enum Target<'a> {
+    Addr(SocketAddr),
+    Host(&'a str, u16),
+}
+
+const SOCKS_VERSION: u8 = 0x05;
+const NO_AUTH: u8 = 0x00;
+const USER_PASS_AUTH: u8 = 0x02;
+
+/// 通过 SOCKS5 代理建立到目标地址的 TCP 连接
+///
+/// # Arguments
+///
+/// * `proxy` - 代理配置
+/// * `target` - 要连接的目标地址
+///
+/// # Returns
+///
+/// 返回已完成 CONNECT 握手、可直接传输 Redis 协议的 TCP 连接
+pub(crate) async fn connect(proxy: &ProxyConfig, target: Target<'_>) -> Result<TcpStream> {
+    let mut stream = TcpStream::connect(&proxy.addr)
+        .await
+        .map_err(|e| network(format!("Failed to connect to proxy {}: {e}", proxy.addr)))?;
+
+    // 方法协商
+    let method = if proxy.credentials.is_some() {
+        USER_PASS_AUTH
+    } else {
+        NO_AUTH
+    };
+    write(&mut stream, &[SOCKS_VERSION, 1, method]).await?;
+    let mut reply = [0u8; 2];
+    read(&mut stream, &mut reply).await?;
+    if reply[0] != SOCKS_VERSION {
+        return Err(network(format!("Unexpected SOCKS version {}", reply[0])));
+    }
+    // 代理不接受任何方法时回复 0xFF
+    if reply[1] != method {
+        return Err(network(
+            "SOCKS5 proxy rejected the authentication method".to_string(),
+        ));
+    }
+
+    if let Some((username, password)) = &proxy.credentials {
+        if username.len() > 255 || password.len() > 255 {
+            return Err(ConnectionError::Configuration(
+                "SOCKS5 username and password must be at most 255 bytes".to_string(),
+            ));
+        }
+        let mut request = vec![0x01, username.len() as u8];
+        request.extend_from_slice(username.as_bytes());
+        request.push(password.len() as u8);
+        request.extend_from_slice(password.as_bytes());
+        write(&mut stream, &request).await?;
+
+        let mut reply = [0u8; 2];
+        read(&mut stream, &mut reply).await?;
+        if reply[1] != 0x00 {
+            return Err(network("SOCKS5 proxy authentication failed".to_string()));
+        }
+    }
+
+    // CONNECT 请求
+    let mut request = vec![SOCKS_VERSION, 0x01, 0x00];
+    let port = match target {
+        Target::Addr(SocketAddr::V4(addr)) => {
+            request.push(0x01);
+            request.extend_from_slice(&addr.ip().octets());
+            addr.port()
+        }
+        Target::Addr(SocketAddr::V6(addr)) => {
+            request.push(0x04);
+            request.extend_from_slice(&addr.ip().octets());
+            addr.port()
+        }
+        Target::Host(host, port) => {
+            if host.len() > 255 {
+                return Err(ConnectionError::Configuration(
+                    "Host name too long for SOCKS5".to_string(),
+                ));
+            }
+            request.push(0x03);
+            request.push(host.len() as u8);
+            request.extend_from_slice(host.as_bytes());
+            port
+        }
+    };
+    request.extend_from_slice(&port.to_be_bytes());
+    write(&mut stream, &request).await?;
+
+    let mut header = [0u8; 4];
+    read(&mut stream, &mut header).await?;
+    if header[1] != 0x00 {
+        return Err(network(format!(
+            "SOCKS5 proxy refused the connection (reply code {})",
+            header[1]
+        )));
+    }
+
+    // 读取并丢弃代理绑定的地址
+    let bound_len = match header[3] {
+        0x01 => 4,
+        0x04 => 16,
+        0x03 => {
+            let mut len = [0u8; 1];
+            read(&mut stream, &mut len).await?;
+            len[0] as usize
+        }
+        other => return Err(network(format!("Unknown SOCKS5 address type {other}"))),
+    };
+    let mut bound = vec![0u8; bound_len + 2];
+    read(&mut stream, &mut bound).await?;
+
+    Ok(stream)
+}
+
+async fn write(stream: &mut TcpStream, bytes: &[u8]) -> Result<()> {
+    stream
+        .write_all(bytes)
+        .await
+        .map_err(|e| network(format!("SOCKS5 handshake failed: {e}")))
+}
+
+async fn read(stream: &mut TcpStream, buf: &mut [u8]) -> Result<()> {
+    stream
+        .read_exact(buf)
+        .await
+        .map(|_| ())
+        .map_err(|e| network(format!("SOCKS5 handshake failed: {e}")))
+}
+
+fn network(msg: String) -> ConnectionError {
+    ConnectionError::Network(msg)
+}
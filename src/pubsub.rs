@@ -0,0 +1,814 @@
+use crate::error::{ConnectionError, Result};
+use crate::pool::PoolConfig;
+use crate::slot::key_slot;
+use crate::types::ClusterNode;
+use futures_util::{Stream, StreamExt};
+use redis::aio::{MultiplexedConnection, PubSubSink};
+use redis::{
+    AsyncConnectionConfig, ConnectionAddr, ErrorKind, Msg, ProtocolVersion, PushInfo, PushKind,
+    ToRedisArgs,
+};
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use tokio::sync::Notify;
+use tokio::sync::mpsc::{self, Receiver, Sender, UnboundedReceiver, error::TrySendError};
+use tokio::task::JoinHandle;
+
+/// 订阅缓冲区已满时的处理策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// 暂停转发，直到读取方腾出空间
+    ///
+    /// **注意**：该策略不限制内存。redis 客户端内部使用无界通道持续从连接读取消息，
+    /// 暂停期间的消息会积压在其内部缓冲中，读取方持续慢于发布方时内存会无限增长。
+    /// 只应在消息不能丢失且确定读取方能够追上时使用。
+    Block,
+    /// 丢弃缓冲区中最旧的一条消息，为新消息腾出空间
+    #[default]
+    DropOldest,
+    /// 丢弃新到达的消息，保留缓冲区中已有的消息
+    DropNewest,
+}
+
+/// 订阅者配置
+#[derive(Debug, Clone)]
+pub struct SubscriberConfig {
+    /// 消息缓冲区容量
+    pub capacity: usize,
+    /// 缓冲区已满时的处理策略
+    pub overflow: OverflowPolicy,
+}
+
+impl Default for SubscriberConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 1024,
+            overflow: OverflowPolicy::DropOldest,
+        }
+    }
+}
+
+/// Redis 订阅者 - 以有界缓冲区承接发布订阅消息的异步消息流
+///
+/// 后台任务从订阅连接读取消息并写入容量为 `capacity` 的有界通道，
+/// 读取方通过 [`Stream`] 接口或 [`RedisSubscriber::next_message`] 消费。
+/// 默认在缓冲区满时丢弃最旧的消息，以保证读取方较慢时内存占用有上限，参见 [`OverflowPolicy`]。
+/// 订阅者被丢弃时后台任务会随之终止。
+///
+/// 频道名可以是任意字节序列，对于非 UTF-8 的频道名，
+/// 请使用 `msg.get_channel::<Vec<u8>>()` 读取原始字节。
+pub struct RedisSubscriber {
+    receiver: Arc<Mutex<Receiver<Msg>>>,
+    sink: PubSubSink,
+    task: JoinHandle<()>,
+}
+
+impl RedisSubscriber {
+    /// 创建订阅者并订阅指定频道和模式
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Redis 客户端
+    /// * `channels` - 要订阅的频道列表
+    /// * `patterns` - 要订阅的频道模式列表
+    /// * `config` - 订阅者配置
+    ///
+    /// # Returns
+    ///
+    /// 返回 RedisSubscriber 实例或错误
+    pub async fn create<C, P>(
+        client: redis::Client,
+        channels: &[C],
+        patterns: &[P],
+        config: SubscriberConfig,
+    ) -> Result<Self>
+    where
+        C: ToRedisArgs + Send + Sync,
+        P: ToRedisArgs + Send + Sync,
+    {
+        if config.capacity == 0 {
+            return Err(ConnectionError::Configuration(
+                "Subscriber capacity must be greater than 0".to_string(),
+            ));
+        }
+
+        let mut pubsub = client.get_async_pubsub().await?;
+        if !channels.is_empty() {
+            pubsub.subscribe(channels).await?;
+        }
+        if !patterns.is_empty() {
+            pubsub.psubscribe(patterns).await?;
+        }
+
+        let (sink, stream) = pubsub.split();
+        let (sender, receiver) = mpsc::channel(config.capacity);
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let task = tokio::spawn(Self::forward(
+            stream,
+            sender,
+            Arc::clone(&receiver),
+            config.overflow,
+        ));
+
+        Ok(Self {
+            receiver,
+            sink,
+            task,
+        })
+    }
+
+    /// 将订阅连接上的消息转发到有界通道
+    async fn forward(
+        mut stream: impl Stream<Item = Msg> + Unpin,
+        sender: Sender<Msg>,
+        receiver: Arc<Mutex<Receiver<Msg>>>,
+        overflow: OverflowPolicy,
+    ) {
+        while let Some(msg) = stream.next().await {
+            let delivered = match overflow {
+                OverflowPolicy::Block => sender.send(msg).await.is_ok(),
+                OverflowPolicy::DropOldest => {
+                    let mut msg = msg;
+                    loop {
+                        match sender.try_send(msg) {
+                            Ok(()) => break true,
+                            Err(TrySendError::Closed(_)) => break false,
+                            Err(TrySendError::Full(rejected)) => {
+                                msg = rejected;
+                                let mut receiver =
+                                    receiver.lock().unwrap_or_else(|e| e.into_inner());
+                                if receiver.try_recv().is_ok() {
+                                    log::debug!("Subscriber buffer full, dropped oldest message");
+                                }
+                            }
+                        }
+                    }
+                }
+                OverflowPolicy::DropNewest => match sender.try_send(msg) {
+                    Ok(()) => true,
+                    Err(TrySendError::Closed(_)) => false,
+                    Err(TrySendError::Full(_)) => {
+                        log::debug!("Subscriber buffer full, dropped newest message");
+                        true
+                    }
+                },
+            };
+
+            if !delivered {
+                break;
+            }
+        }
+    }
+
+    /// 在运行中订阅新的频道
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - 频道名，可以是字符串或字节序列
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn subscribe(&self, channel: impl ToRedisArgs) -> Result<()> {
+        self.sink.clone().subscribe(channel).await?;
+        Ok(())
+    }
+
+    /// 在运行中取消订阅频道
+    ///
+    /// 取消最后一个频道后订阅者不会关闭，之后仍可继续订阅新的频道。
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - 频道名，可以是字符串或字节序列
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn unsubscribe(&self, channel: impl ToRedisArgs) -> Result<()> {
+        self.sink.clone().unsubscribe(channel).await?;
+        Ok(())
+    }
+
+    /// 在运行中订阅新的频道模式
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - 频道模式，可以是字符串或字节序列
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn psubscribe(&self, pattern: impl ToRedisArgs) -> Result<()> {
+        self.sink.clone().psubscribe(pattern).await?;
+        Ok(())
+    }
+
+    /// 在运行中取消订阅频道模式
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - 频道模式，可以是字符串或字节序列
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn punsubscribe(&self, pattern: impl ToRedisArgs) -> Result<()> {
+        self.sink.clone().punsubscribe(pattern).await?;
+        Ok(())
+    }
+
+    /// 获取下一条消息
+    ///
+    /// # Returns
+    ///
+    /// 返回下一条消息，订阅连接关闭后返回 None
+    pub async fn next_message(&mut self) -> Option<Msg> {
+        self.next().await
+    }
+
+    /// 订阅路由中注册的所有模式，并把消息分发给对应的处理函数，直到订阅连接关闭
+    ///
+    /// 消息按实际匹配到的模式（pmessage 回复中的模式）分发，没有匹配处理函数的消息会以 debug 级别记录后丢弃。
+    ///
+    /// 路由中的每一项（包括普通频道名）都以模式订阅，因此可路由的消息都会以 pmessage 到达。
+    /// 同一频道若还通过 [`RedisSubscriber::subscribe`] 直接订阅，服务器会再发送一条普通 message，
+    /// 这些普通消息会被忽略，避免处理函数对同一条消息执行两次。
+    ///
+    /// # Arguments
+    ///
+    /// * `router` - 模式到处理函数的路由表
+    ///
+    /// # Returns
+    ///
+    /// 订阅连接关闭后返回 Ok，订阅模式失败时返回错误
+    pub async fn run_router(mut self, router: Router) -> Result<()> {
+        for pattern in router.handlers.keys() {
+            self.psubscribe(pattern).await?;
+        }
+
+        while let Some(msg) = self.next().await {
+            if msg.from_pattern() {
+                router.dispatch(msg);
+            } else {
+                log::debug!(
+                    "Ignoring direct message on channel {}, routes only receive pattern messages",
+                    msg.get_channel_name()
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 订阅消息处理函数
+type MessageHandler = Arc<dyn Fn(Msg) + Send + Sync>;
+
+/// 订阅消息路由表 - 以声明式的方式为每个频道模式注册处理函数
+///
+/// 配合 [`RedisSubscriber::run_router`] 使用。处理函数在订阅者的读取循环中同步执行，
+/// 耗时的处理应自行通过 `tokio::spawn` 转入后台，避免阻塞后续消息。
+#[derive(Clone, Default)]
+pub struct Router {
+    handlers: HashMap<String, MessageHandler>,
+}
+
+impl Router {
+    /// 创建空的路由表
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 为频道模式注册处理函数，同一模式重复注册时覆盖之前的处理函数
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - 频道模式，例如 `orders.*`；也可以是普通频道名
+    /// * `handler` - 消息处理函数
+    ///
+    /// # Returns
+    ///
+    /// 返回更新后的路由表
+    pub fn route<F>(mut self, pattern: impl Into<String>, handler: F) -> Self
+    where
+        F: Fn(Msg) + Send + Sync + 'static,
+    {
+        self.handlers.insert(pattern.into(), Arc::new(handler));
+        self
+    }
+
+    /// 获取已注册的模式
+    pub fn patterns(&self) -> impl Iterator<Item = &str> {
+        self.handlers.keys().map(String::as_str)
+    }
+
+    /// 将消息分发给匹配的处理函数
+    ///
+    /// 模式消息按 pmessage 回复中的模式查找路由，普通频道消息按频道名查找同名路由。
+    /// 同一频道既被直接订阅又被某个已订阅的模式匹配时，服务器会分别发送 message 和 pmessage，
+    /// 逐条调用本方法会分发两次；[`RedisSubscriber::run_router`] 只分发模式消息，不受此影响。
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - 订阅消息
+    ///
+    /// # Returns
+    ///
+    /// 返回是否找到了处理函数
+    pub fn dispatch(&self, msg: Msg) -> bool {
+        let route = if msg.from_pattern() {
+            msg.get_pattern::<String>().ok()
+        } else {
+            Some(msg.get_channel_name().to_string())
+        };
+
+        match route.as_deref().and_then(|route| self.handlers.get(route)) {
+            Some(handler) => {
+                handler(msg);
+                true
+            }
+            None => {
+                log::debug!(
+                    "No handler registered for message on channel {} (pattern {route:?})",
+                    msg.get_channel_name()
+                );
+                false
+            }
+        }
+    }
+}
+
+impl Stream for RedisSubscriber {
+    type Item = Msg;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .poll_recv(cx)
+    }
+}
+
+impl Drop for RedisSubscriber {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// 只保留每个频道最新消息的订阅者 - 适用于只关心最新状态的场景
+///
+/// 后台任务把收到的消息按频道名合并，每个频道只保留最近一条尚未读取的消息，
+/// 读取方落后时中间的旧值会被直接覆盖，因此内存占用上限为每个频道一条消息。
+/// 读取方通过 [`LatestValueSubscriber::take`] 取走当前所有未读的最新值，
+/// 并可通过 [`LatestValueSubscriber::changed`] 等待新消息到达。
+///
+/// 非 UTF-8 的频道名会以有损方式转换为字符串作为键。
+pub struct LatestValueSubscriber {
+    latest: Arc<Mutex<HashMap<String, Msg>>>,
+    notify: Arc<Notify>,
+    sink: PubSubSink,
+    task: JoinHandle<()>,
+}
+
+impl LatestValueSubscriber {
+    /// 创建订阅者并订阅指定频道和模式
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Redis 客户端
+    /// * `channels` - 要订阅的频道列表
+    /// * `patterns` - 要订阅的频道模式列表
+    ///
+    /// # Returns
+    ///
+    /// 返回 LatestValueSubscriber 实例或错误
+    pub async fn create<C, P>(client: redis::Client, channels: &[C], patterns: &[P]) -> Result<Self>
+    where
+        C: ToRedisArgs + Send + Sync,
+        P: ToRedisArgs + Send + Sync,
+    {
+        let mut pubsub = client.get_async_pubsub().await?;
+        if !channels.is_empty() {
+            pubsub.subscribe(channels).await?;
+        }
+        if !patterns.is_empty() {
+            pubsub.psubscribe(patterns).await?;
+        }
+
+        let (sink, mut stream) = pubsub.split();
+        let latest = Arc::new(Mutex::new(HashMap::new()));
+        let notify = Arc::new(Notify::new());
+
+        let task = {
+            let latest = Arc::clone(&latest);
+            let notify = Arc::clone(&notify);
+            tokio::spawn(async move {
+                while let Some(msg) = stream.next().await {
+                    let channel = msg.get_channel::<Vec<u8>>().unwrap_or_default();
+                    let channel = String::from_utf8_lossy(&channel).into_owned();
+                    latest
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .insert(channel, msg);
+                    notify.notify_one();
+                }
+            })
+        };
+
+        Ok(Self {
+            latest,
+            notify,
+            sink,
+            task,
+        })
+    }
+
+    /// 取走所有频道当前未读的最新消息
+    ///
+    /// # Returns
+    ///
+    /// 返回频道名到最新消息的映射，没有新消息时为空
+    pub fn take(&self) -> HashMap<String, Msg> {
+        std::mem::take(&mut *self.latest.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// 取走指定频道未读的最新消息
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - 频道名
+    ///
+    /// # Returns
+    ///
+    /// 返回最新消息，没有未读消息时返回 None
+    pub fn take_channel(&self, channel: &str) -> Option<Msg> {
+        self.latest
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(channel)
+    }
+
+    /// 等待新消息到达
+    ///
+    /// 若上次调用之后已有新消息到达则立即返回。
+    pub async fn changed(&self) {
+        self.notify.notified().await;
+    }
+
+    /// 在运行中订阅新的频道
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - 频道名，可以是字符串或字节序列
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn subscribe(&self, channel: impl ToRedisArgs) -> Result<()> {
+        self.sink.clone().subscribe(channel).await?;
+        Ok(())
+    }
+
+    /// 在运行中取消订阅频道
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - 频道名，可以是字符串或字节序列
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn unsubscribe(&self, channel: impl ToRedisArgs) -> Result<()> {
+        self.sink.clone().unsubscribe(channel).await?;
+        Ok(())
+    }
+}
+
+impl Drop for LatestValueSubscriber {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// 分片发布订阅的订阅者（SSUBSCRIBE，Redis 7.0+）
+///
+/// 分片频道的消息只在频道所属槽的分片内传播，因此订阅者必须连接到负责该槽的节点，
+/// 连接到其他节点时 SSUBSCRIBE 会返回 MOVED 错误。同一个订阅者只能订阅属于同一个槽的频道。
+/// 需要自动定位节点时请使用 [`ClusterShardedSubscriber`]。
+///
+/// 分片订阅需要 RESP3 协议，本类型会以 RESP3 建立专用连接，消息缓冲区不设上限。
+pub struct ShardedSubscriber {
+    conn: MultiplexedConnection,
+    receiver: UnboundedReceiver<PushInfo>,
+}
+
+impl ShardedSubscriber {
+    /// 创建订阅者并订阅指定的分片频道
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Redis 客户端，需指向负责频道所属槽的节点
+    /// * `channels` - 要订阅的分片频道列表
+    ///
+    /// # Returns
+    ///
+    /// 返回 ShardedSubscriber 实例或错误
+    pub async fn create<C>(client: redis::Client, channels: &[C]) -> Result<Self>
+    where
+        C: ToRedisArgs + Send + Sync,
+    {
+        let mut info = client.get_connection_info().clone();
+        info.redis.protocol = ProtocolVersion::RESP3;
+        let client = redis::Client::open(info)?;
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let config = AsyncConnectionConfig::new().set_push_sender(sender);
+        let conn = client
+            .get_multiplexed_async_connection_with_config(&config)
+            .await?;
+
+        let subscriber = Self { conn, receiver };
+        if !channels.is_empty() {
+            subscriber.ssubscribe(channels).await?;
+        }
+        Ok(subscriber)
+    }
+
+    /// 在运行中订阅新的分片频道
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - 分片频道名，可以是字符串或字节序列
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn ssubscribe(&self, channel: impl ToRedisArgs) -> Result<()> {
+        redis::cmd("SSUBSCRIBE")
+            .arg(channel)
+            .exec_async(&mut self.conn.clone())
+            .await?;
+        Ok(())
+    }
+
+    /// 在运行中取消订阅分片频道
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - 分片频道名，可以是字符串或字节序列
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn sunsubscribe(&self, channel: impl ToRedisArgs) -> Result<()> {
+        redis::cmd("SUNSUBSCRIBE")
+            .arg(channel)
+            .exec_async(&mut self.conn.clone())
+            .await?;
+        Ok(())
+    }
+
+    /// 获取下一条消息
+    ///
+    /// # Returns
+    ///
+    /// 返回下一条消息，连接关闭后返回 None
+    pub async fn next_message(&mut self) -> Option<Msg> {
+        self.next().await
+    }
+}
+
+impl Stream for ShardedSubscriber {
+    type Item = Msg;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match self.receiver.poll_recv(cx) {
+                // 跳过订阅确认等非消息类推送
+                Poll::Ready(Some(push)) => {
+                    if let Some(msg) = Msg::from_push_info(push) {
+                        return Poll::Ready(Some(msg));
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// 集群感知的分片订阅者（SSUBSCRIBE，Redis 7.0+）
+///
+/// 按 CRC16 计算频道所属的槽位，通过 CLUSTER NODES 找到负责该槽的主节点并在其上订阅，
+/// 订阅时收到 MOVED 重定向会改连重定向目标。槽位迁移后服务器会主动取消分片订阅，
+/// 后台任务检测到后会重新解析负责节点并重新订阅；连接断开时同样按退避间隔重连。
+///
+/// 所有频道必须位于同一个槽，可以用 `{hashtag}` 保证这一点。重新路由期间发布的消息会丢失。
+pub struct ClusterShardedSubscriber {
+    receiver: UnboundedReceiver<Msg>,
+    task: JoinHandle<()>,
+}
+
+impl ClusterShardedSubscriber {
+    /// 单次订阅允许跟随的最大 MOVED 重定向次数
+    const MAX_REDIRECTS: usize = 5;
+
+    /// 创建订阅者并在负责频道所属槽的节点上订阅
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Redis 客户端，可指向集群中的任意节点，用于查询槽位分布
+    /// * `channels` - 要订阅的分片频道列表，必须位于同一个槽
+    /// * `pool_config` - 连接池配置，用于计算重连的退避间隔
+    ///
+    /// # Returns
+    ///
+    /// 返回 ClusterShardedSubscriber 实例或错误
+    pub async fn create<S>(
+        client: redis::Client,
+        channels: &[S],
+        pool_config: PoolConfig,
+    ) -> Result<Self>
+    where
+        S: AsRef<str>,
+    {
+        let channels: Vec<String> = channels.iter().map(|c| c.as_ref().to_string()).collect();
+        let Some(first) = channels.first() else {
+            return Err(ConnectionError::Configuration(
+                "At least one sharded channel is required".to_string(),
+            ));
+        };
+        let slot = key_slot(first.as_bytes());
+        if let Some(other) = channels.iter().find(|c| key_slot(c.as_bytes()) != slot) {
+            return Err(ConnectionError::Configuration(format!(
+                "Channel `{other}` does not belong to slot {slot} of `{first}`"
+            )));
+        }
+
+        let (conn, pushes) = Self::connect(&client, slot, &channels).await?;
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        let task = tokio::spawn(async move {
+            let mut conn = conn;
+            let mut pushes = pushes;
+            loop {
+                while let Some(push) = pushes.recv().await {
+                    match push.kind {
+                        // 未主动取消订阅，说明槽位已迁移到其他节点
+                        PushKind::SUnsubscribe => {
+                            log::debug!("Sharded subscription on slot {slot} dropped by server");
+                            break;
+                        }
+                        PushKind::Disconnection => break,
+                        _ => {
+                            if let Some(msg) = Msg::from_push_info(push)
+                                && sender.send(msg).is_err()
+                            {
+                                return;
+                            }
+                        }
+                    }
+                }
+                drop(conn);
+
+                let mut attempt = 0;
+                (conn, pushes) = loop {
+                    match Self::connect(&client, slot, &channels).await {
+                        Ok(connected) => break connected,
+                        Err(e) => {
+                            let delay = pool_config.retry_delay(attempt);
+                            log::debug!(
+                                "Failed to resubscribe sharded channels on slot {slot}: {e}, retrying in {delay:?}"
+                            );
+                            tokio::time::sleep(delay).await;
+                            attempt = attempt.saturating_add(1);
+                        }
+                    }
+                };
+            }
+        });
+
+        Ok(Self { receiver, task })
+    }
+
+    /// 解析负责槽位的节点并在其上订阅，跟随 MOVED 重定向
+    async fn connect(
+        client: &redis::Client,
+        slot: u16,
+        channels: &[String],
+    ) -> Result<(MultiplexedConnection, UnboundedReceiver<PushInfo>)> {
+        let mut addr = Self::slot_owner(client, slot).await?;
+        for _ in 0..Self::MAX_REDIRECTS {
+            let (host, port) = Self::split_addr(client, &addr)?;
+            let mut info = client.get_connection_info().clone();
+            info.redis.protocol = ProtocolVersion::RESP3;
+            match &mut info.addr {
+                ConnectionAddr::Tcp(h, p)
+                | ConnectionAddr::TcpTls {
+                    host: h, port: p, ..
+                } => {
+                    *h = host;
+                    *p = port;
+                }
+                ConnectionAddr::Unix(_) => {
+                    return Err(ConnectionError::Configuration(
+                        "Cluster routing requires a TCP connection".to_string(),
+                    ));
+                }
+            }
+
+            let (sender, receiver) = mpsc::unbounded_channel();
+            let config = AsyncConnectionConfig::new().set_push_sender(sender);
+            let mut conn = redis::Client::open(info)?
+                .get_multiplexed_async_connection_with_config(&config)
+                .await?;
+
+            match redis::cmd("SSUBSCRIBE")
+                .arg(channels)
+                .exec_async(&mut conn)
+                .await
+            {
+                Ok(()) => return Ok((conn, receiver)),
+                Err(e) if e.kind() == ErrorKind::Moved => {
+                    let Some((target, _)) = e.redirect_node() else {
+                        return Err(e.into());
+                    };
+                    log::debug!("Slot {slot} moved from {addr} to {target}");
+                    addr = target.to_string();
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Err(ConnectionError::Network(format!(
+            "Too many MOVED redirects while subscribing on slot {slot}"
+        )))
+    }
+
+    /// 通过 CLUSTER NODES 查找负责槽位的主节点地址
+    async fn slot_owner(client: &redis::Client, slot: u16) -> Result<String> {
+        let mut conn = client.get_multiplexed_async_connection().await?;
+        let output: String = redis::cmd("CLUSTER")
+            .arg("NODES")
+            .query_async(&mut conn)
+            .await?;
+
+        for line in output.lines().filter(|line| !line.trim().is_empty()) {
+            let node = ClusterNode::parse_line(line).map_err(ConnectionError::Deserialization)?;
+            let owns = node
+                .slots
+                .iter()
+                .any(|&(start, end)| (start..=end).contains(&slot));
+            if node.master.is_none() && owns {
+                return Ok(node.addr);
+            }
+        }
+
+        Err(ConnectionError::Configuration(format!(
+            "No cluster node serves slot {slot}"
+        )))
+    }
+
+    /// 拆分 `host:port` 形式的节点地址，主机部分为空时沿用种子节点的主机
+    fn split_addr(client: &redis::Client, addr: &str) -> Result<(String, u16)> {
+        let invalid = || ConnectionError::Deserialization(format!("invalid node address: {addr}"));
+        let (host, port) = addr.rsplit_once(':').ok_or_else(invalid)?;
+        let port = port.parse::<u16>().map_err(|_| invalid())?;
+        if !host.is_empty() {
+            return Ok((host.to_string(), port));
+        }
+
+        match &client.get_connection_info().addr {
+            ConnectionAddr::Tcp(seed, _) | ConnectionAddr::TcpTls { host: seed, .. } => {
+                Ok((seed.clone(), port))
+            }
+            ConnectionAddr::Unix(_) => Err(invalid()),
+        }
+    }
+
+    /// 获取下一条消息
+    ///
+    /// # Returns
+    ///
+    /// 返回下一条消息，订阅者被丢弃后返回 None
+    pub async fn next_message(&mut self) -> Option<Msg> {
+        self.receiver.recv().await
+    }
+}
+
+impl Stream for ClusterShardedSubscriber {
+    type Item = Msg;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for ClusterShardedSubscriber {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,71 @@
+use crate::error::Result;
+use redis::aio::ConnectionManager;
+
+/// 成功率计数器 - 在同一个哈希中维护 `success` 与 `total` 两个字段
+///
+/// 两个字段总是在同一个 MULTI/EXEC 事务中一起递增，读取时不会看到只更新了一半的计数。
+pub struct RatioCounter;
+
+impl RatioCounter {
+    /// 成功次数字段名
+    pub const SUCCESS_FIELD: &'static str = "success";
+    /// 总次数字段名
+    pub const TOTAL_FIELD: &'static str = "total";
+
+    /// 记录一次结果
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 计数器哈希的键名
+    /// * `success` - 本次是否成功，成功时同时递增 `success` 字段
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn record(conn: &mut ConnectionManager, key: &str, success: bool) -> Result<()> {
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .hincr(key, Self::SUCCESS_FIELD, i64::from(success))
+            .ignore()
+            .hincr(key, Self::TOTAL_FIELD, 1)
+            .ignore();
+        let _: () = pipe.query_async(conn).await?;
+        Ok(())
+    }
+
+    /// 获取成功次数与总次数
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 计数器哈希的键名
+    ///
+    /// # Returns
+    ///
+    /// 返回 (成功次数, 总次数)，计数器不存在时均为 0
+    pub async fn counts(conn: &mut ConnectionManager, key: &str) -> Result<(u64, u64)> {
+        let (success, total): (Option<u64>, Option<u64>) = redis::cmd("HMGET")
+            .arg(key)
+            .arg(Self::SUCCESS_FIELD)
+            .arg(Self::TOTAL_FIELD)
+            .query_async(conn)
+            .await?;
+        Ok((success.unwrap_or(0), total.unwrap_or(0)))
+    }
+
+    /// 获取成功率
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 计数器哈希的键名
+    ///
+    /// # Returns
+    ///
+    /// 返回 success / total，总次数为 0 时返回 None
+    pub async fn ratio(conn: &mut ConnectionManager, key: &str) -> Result<Option<f64>> {
+        let (success, total) = Self::counts(conn, key).await?;
+        if total == 0 {
+            return Ok(None);
+        }
+        Ok(Some(success as f64 / total as f64))
+    }
+}
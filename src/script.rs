@@ -0,0 +1,131 @@
+use crate::error::{ConnectionError, Result};
+use redis::aio::ConnectionManager;
+use redis::{FromRedisValue, ToRedisArgs};
+
+/// Lua 脚本 - 优先以 EVALSHA 调用，服务器缓存中没有脚本时自动回退为携带源码的调用
+///
+/// 脚本执行中的错误统一转换为 `ConnectionError::Script`。
+#[derive(Debug, Clone)]
+pub struct RedisScript {
+    source: String,
+    script: redis::Script,
+}
+
+impl RedisScript {
+    /// 创建脚本
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Lua 脚本源码
+    ///
+    /// # Returns
+    ///
+    /// 返回 RedisScript 实例
+    pub fn new(source: impl Into<String>) -> Self {
+        let source = source.into();
+        Self {
+            script: redis::Script::new(&source),
+            source,
+        }
+    }
+
+    /// 获取脚本的 SHA1 摘要
+    pub fn sha(&self) -> &str {
+        self.script.get_hash()
+    }
+
+    /// 获取脚本源码
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// 执行脚本
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - 脚本访问的键（KEYS）
+    /// * `args` - 脚本参数（ARGV）
+    ///
+    /// # Returns
+    ///
+    /// 返回脚本的返回值
+    pub async fn invoke<K, A, T>(
+        &self,
+        conn: &mut ConnectionManager,
+        keys: &[K],
+        args: &[A],
+    ) -> Result<T>
+    where
+        K: ToRedisArgs + Send + Sync,
+        A: ToRedisArgs + Send + Sync,
+        T: FromRedisValue,
+    {
+        self.call(conn, "EVALSHA", "EVAL", keys, args).await
+    }
+
+    /// 以只读方式执行脚本（EVALSHA_RO / EVAL_RO，Redis 7.0+）
+    ///
+    /// 只读脚本可以在副本节点上执行，配合 `RedisConnector::readonly` 建立的副本连接
+    /// 可以分担读多的脚本负载。脚本尝试写入时服务器会拒绝执行，并返回 `ConnectionError::Script`。
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - 脚本访问的键（KEYS）
+    /// * `args` - 脚本参数（ARGV）
+    ///
+    /// # Returns
+    ///
+    /// 返回脚本的返回值
+    pub async fn invoke_ro<K, A, T>(
+        &self,
+        conn: &mut ConnectionManager,
+        keys: &[K],
+        args: &[A],
+    ) -> Result<T>
+    where
+        K: ToRedisArgs + Send + Sync,
+        A: ToRedisArgs + Send + Sync,
+        T: FromRedisValue,
+    {
+        self.call(conn, "EVALSHA_RO", "EVAL_RO", keys, args).await
+    }
+
+    /// 先以摘要调用脚本，服务器返回 NOSCRIPT 时改为携带源码调用
+    async fn call<K, A, T>(
+        &self,
+        conn: &mut ConnectionManager,
+        by_sha: &str,
+        by_source: &str,
+        keys: &[K],
+        args: &[A],
+    ) -> Result<T>
+    where
+        K: ToRedisArgs + Send + Sync,
+        A: ToRedisArgs + Send + Sync,
+        T: FromRedisValue,
+    {
+        let build = |command: &str, body: &str| {
+            let mut cmd = redis::cmd(command);
+            cmd.arg(body).arg(keys.len()).arg(keys).arg(args);
+            cmd
+        };
+
+        let result: redis::RedisResult<T> = build(by_sha, self.sha()).query_async(conn).await;
+        let result = match result {
+            Err(e) if e.kind() == redis::ErrorKind::NoScriptError => {
+                build(by_source, &self.source).query_async(conn).await
+            }
+            other => other,
+        };
+
+        result.map_err(|e| {
+            if e.kind() == redis::ErrorKind::ResponseError
+                || e.kind() == redis::ErrorKind::ExtensionError
+            {
+                ConnectionError::Script(e.to_string())
+            } else {
+                ConnectionError::from(e)
+            }
+        })
+    }
+}
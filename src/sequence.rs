@@ -0,0 +1,69 @@
+use crate::error::{ConnectionError, Result};
+use redis::AsyncCommands;
+use redis::aio::ConnectionManager;
+use std::ops::Range;
+
+/// 基于 INCR 的原子序列号生成器 - 在多个实例之间生成单调递增的 ID
+///
+/// 第一个生成的 ID 为 1。通过 [`Sequence::reserve`] 可以一次预留一段连续的 ID，
+/// 在本地分发以减少网络往返。
+#[derive(Debug, Clone)]
+pub struct Sequence {
+    key: String,
+}
+
+impl Sequence {
+    /// 创建序列号生成器
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 保存计数器的键名
+    ///
+    /// # Returns
+    ///
+    /// 返回 Sequence 实例
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into() }
+    }
+
+    /// 获取计数器的键名
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 生成下一个 ID
+    ///
+    /// # Returns
+    ///
+    /// 返回新的 ID
+    pub async fn next(&self, conn: &mut ConnectionManager) -> Result<u64> {
+        let id: u64 = conn.incr(&self.key, 1).await?;
+        Ok(id)
+    }
+
+    /// 以一次 INCRBY 预留一段连续的 ID
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - 预留的数量，必须大于 0
+    ///
+    /// # Returns
+    ///
+    /// 返回预留到的 ID 区间（左闭右开），计数器被设置为负数导致区间起点小于 0 时返回反序列化错误
+    pub async fn reserve(&self, conn: &mut ConnectionManager, n: u64) -> Result<Range<u64>> {
+        if n == 0 {
+            return Err(ConnectionError::Configuration(
+                "Sequence reservation size must be greater than 0".to_string(),
+            ));
+        }
+
+        let last: u64 = conn.incr(&self.key, n).await?;
+        let start = (last + 1).checked_sub(n).ok_or_else(|| {
+            ConnectionError::Deserialization(format!(
+                "Sequence counter {} is below the reserved range of {n}",
+                self.key
+            ))
+        })?;
+        Ok(start..last + 1)
+    }
+}
@@ -0,0 +1,225 @@
+use crate::connector::RedisConnector;
+use crate::error::{ConnectionError, Result};
+use crate::handle::Redis;
+use crate::slot::{crc16, hash_tag};
+use redis::{FromRedisValue, ToRedisArgs};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// 客户端分片连接器 - 按键的哈希把操作路由到多个独立的 Redis 实例
+///
+/// 默认按 `crc16(key) % N` 选择实例；调用 [`ShardedConnector::with_hash_ring`] 后改用一致性哈希环，
+/// 增加实例时只有约 `1/N` 的键会迁移到新实例。键中包含非空 `{hashtag}` 时只对花括号内的部分计算哈希，
+/// 可以借此把相关的键放在同一个实例上。
+///
+/// 路由只依赖实例在列表中的位置，增删实例时请保持已有实例的顺序不变。
+/// 多键操作不会跨实例拆分，需要自行按 [`ShardedConnector::shard`] 分组。
+#[derive(Clone)]
+pub struct ShardedConnector {
+    shards: Vec<Redis>,
+    ring: Option<Vec<(u16, usize)>>,
+}
+
+impl ShardedConnector {
+    /// 哈希环上每个实例默认的虚拟节点数
+    pub const DEFAULT_VIRTUAL_NODES: usize = 160;
+
+    /// 连接所有实例并创建分片连接器
+    ///
+    /// # Arguments
+    ///
+    /// * `connectors` - 各实例的连接器，顺序决定路由结果
+    ///
+    /// # Returns
+    ///
+    /// 返回 ShardedConnector 实例或错误，连接器列表为空时返回配置错误
+    pub async fn connect(connectors: &[RedisConnector]) -> Result<Self> {
+        if connectors.is_empty() {
+            return Err(ConnectionError::Configuration(
+                "At least one shard connector is required".to_string(),
+            ));
+        }
+
+        let mut shards = Vec::with_capacity(connectors.len());
+        for connector in connectors {
+            shards.push(connector.connection().await?);
+        }
+        Ok(Self { shards, ring: None })
+    }
+
+    /// 改用一致性哈希环路由
+    ///
+    /// # Arguments
+    ///
+    /// * `virtual_nodes` - 每个实例在环上的虚拟节点数，越多分布越均匀，为 0 时使用默认值
+    ///
+    /// # Returns
+    ///
+    /// 返回使用哈希环路由的连接器
+    pub fn with_hash_ring(mut self, virtual_nodes: usize) -> Self {
+        let virtual_nodes = if virtual_nodes == 0 {
+            Self::DEFAULT_VIRTUAL_NODES
+        } else {
+            virtual_nodes
+        };
+
+        let mut ring: Vec<(u16, usize)> = (0..self.shards.len())
+            .flat_map(|index| {
+                (0..virtual_nodes)
+                    .map(move |node| (crc16(format!("shard-{index}#{node}").as_bytes()), index))
+            })
+            .collect();
+        // 哈希值冲突时保留序号较小的实例，保证结果确定
+        ring.sort_unstable();
+        ring.dedup_by_key(|(point, _)| *point);
+
+        self.ring = Some(ring);
+        self
+    }
+
+    /// 获取实例数量
+    pub fn len(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// 是否没有任何实例，通过 [`ShardedConnector::connect`] 创建的连接器总是返回 false
+    pub fn is_empty(&self) -> bool {
+        self.shards.is_empty()
+    }
+
+    /// 获取所有实例的句柄，用于需要遍历全部实例的操作（如 SCAN、FLUSHDB）
+    pub fn shards(&self) -> &[Redis] {
+        &self.shards
+    }
+
+    /// 计算键被路由到的实例序号
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 键名
+    ///
+    /// # Returns
+    ///
+    /// 返回实例在列表中的序号
+    pub fn shard_index(&self, key: &str) -> usize {
+        let hash = crc16(hash_tag(key.as_bytes()));
+        match &self.ring {
+            Some(ring) => {
+                let pos = ring.partition_point(|&(point, _)| point < hash);
+                ring.get(pos).unwrap_or(&ring[0]).1
+            }
+            None => usize::from(hash) % self.shards.len(),
+        }
+    }
+
+    /// 获取键被路由到的实例句柄，可以调用 [`Redis`] 上的任意方法
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 键名
+    ///
+    /// # Returns
+    ///
+    /// 返回实例句柄
+    pub fn shard(&self, key: &str) -> &Redis {
+        &self.shards[self.shard_index(key)]
+    }
+
+    // ==================== 字符串操作 ====================
+
+    /// 设置字符串值，参见 [`crate::RedisUtils::set`]
+    pub async fn set<V>(&self, key: &str, value: V) -> Result<()>
+    where
+        V: ToRedisArgs + Send + Sync,
+    {
+        self.shard(key).set(key, value).await
+    }
+
+    /// 设置字符串值并指定过期时间，参见 [`crate::RedisUtils::set_duration`]
+    pub async fn set_duration<V>(&self, key: &str, value: V, ttl: Duration) -> Result<()>
+    where
+        V: ToRedisArgs + Send + Sync,
+    {
+        self.shard(key).set_duration(key, value, ttl).await
+    }
+
+    /// 获取字符串值，参见 [`crate::RedisUtils::get`]
+    pub async fn get<V>(&self, key: &str) -> Result<Option<V>>
+    where
+        V: FromRedisValue,
+    {
+        self.shard(key).get(key).await
+    }
+
+    /// 删除键，参见 [`crate::RedisUtils::del`]
+    pub async fn del(&self, key: &str) -> Result<i32> {
+        self.shard(key).del(key).await
+    }
+
+    /// 检查键是否存在，参见 [`crate::RedisUtils::exists`]
+    pub async fn exists(&self, key: &str) -> Result<bool> {
+        self.shard(key).exists(key).await
+    }
+
+    /// 设置键的过期时间，参见 [`crate::RedisUtils::expire`]
+    pub async fn expire(&self, key: &str, seconds: usize) -> Result<bool> {
+        self.shard(key).expire(key, seconds).await
+    }
+
+    /// 获取键的剩余生存时间，参见 [`crate::RedisUtils::ttl`]
+    pub async fn ttl(&self, key: &str) -> Result<i32> {
+        self.shard(key).ttl(key).await
+    }
+
+    // ==================== 哈希表操作 ====================
+
+    /// 设置哈希表字段值，参见 [`crate::RedisUtils::hset`]
+    pub async fn hset<F, V>(&self, key: &str, field: F, value: V) -> Result<bool>
+    where
+        F: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        self.shard(key).hset(key, field, value).await
+    }
+
+    /// 获取哈希表字段值，参见 [`crate::RedisUtils::hget`]
+    pub async fn hget<F, V>(&self, key: &str, field: F) -> Result<Option<V>>
+    where
+        F: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        self.shard(key).hget(key, field).await
+    }
+
+    /// 获取哈希表所有字段和值，参见 [`crate::RedisUtils::hgetall`]
+    pub async fn hgetall(&self, key: &str) -> Result<HashMap<String, String>> {
+        self.shard(key).hgetall(key).await
+    }
+
+    /// 删除哈希表字段，参见 [`crate::RedisUtils::hdel`]
+    pub async fn hdel<F>(&self, key: &str, fields: F) -> Result<i32>
+    where
+        F: ToRedisArgs + Send + Sync,
+    {
+        self.shard(key).hdel(key, fields).await
+    }
+
+    // ==================== JSON 操作 ====================
+
+    /// 设置 JSON 对象，参见 [`crate::RedisUtils::set_json`]
+    pub async fn set_json<V>(&self, key: &str, value: &V) -> Result<()>
+    where
+        V: Serialize,
+    {
+        self.shard(key).set_json(key, value).await
+    }
+
+    /// 获取 JSON 对象，参见 [`crate::RedisUtils::get_json`]
+    pub async fn get_json<V>(&self, key: &str) -> Result<Option<V>>
+    where
+        V: for<'de> Deserialize<'de>,
+    {
+        self.shard(key).get_json(key).await
+    }
+}
@@ -0,0 +1,64 @@
+/// 集群槽位总数
+pub const SLOT_COUNT: u16 = 16384;
+
+/// 计算 CRC16（XMODEM 变体，多项式 0x1021，初始值 0）
+///
+/// # Arguments
+///
+/// * `data` - 输入字节
+///
+/// # Returns
+///
+/// 返回 16 位校验值
+pub fn crc16(data: &[u8]) -> u16 {
+    data.iter().fold(0u16, |crc, &byte| {
+        let mut crc = crc ^ (u16::from(byte) << 8);
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
+
+/// 取出参与槽位计算的部分
+///
+/// 键中第一个 `{` 之后到下一个 `}` 之间的内容非空时，只用这部分计算槽位，
+/// 否则使用整个键。
+///
+/// # Arguments
+///
+/// * `key` - 键名或频道名
+///
+/// # Returns
+///
+/// 返回参与哈希的字节切片
+pub fn hash_tag(key: &[u8]) -> &[u8] {
+    if let Some(open) = key.iter().position(|&b| b == b'{') {
+        let rest = &key[open + 1..];
+        if let Some(close) = rest.iter().position(|&b| b == b'}')
+            && close > 0
+        {
+            return &rest[..close];
+        }
+    }
+    key
+}
+
+/// 计算键所属的集群槽位
+///
+/// 与服务器的算法一致：对 [`hash_tag`] 取出的部分计算 CRC16，再对 16384 取模。
+///
+/// # Arguments
+///
+/// * `key` - 键名或频道名
+///
+/// # Returns
+///
+/// 返回 0..16384 范围内的槽位
+pub fn key_slot(key: &[u8]) -> u16 {
+    crc16(hash_tag(key)) % SLOT_COUNT
+}
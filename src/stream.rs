@@ -0,0 +1,156 @@
+use crate::connector::RedisConnector;
+use crate::error::Result;
+use redis::aio::MultiplexedConnection;
+use redis::streams::{StreamId, StreamRangeReply, StreamReadReply};
+use std::time::Duration;
+
+/// 断线后可续读的流读取器 - 记录最后读到的消息 ID，重连后从该位置继续 XREAD
+///
+/// 读取器独占一条专用连接执行阻塞的 XREAD。连接错误会按 [`crate::PoolConfig::retry_delay`]
+/// 退避后通过保存的 [`RedisConnector`] 重新建立连接，并从最后读到的 ID 之后继续读取，
+/// 因此断线期间写入的消息不会丢失；连续失败超过 `PoolConfig::max_retries` 次时返回该错误。
+pub struct StreamReader {
+    connector: RedisConnector,
+    conn: Option<MultiplexedConnection>,
+    key: String,
+    last_id: String,
+    count: usize,
+    block: Duration,
+}
+
+impl StreamReader {
+    /// 创建流读取器
+    ///
+    /// # Arguments
+    ///
+    /// * `connector` - 用于建立和重建连接的连接器
+    /// * `key` - 流键名
+    /// * `start_id` - 从该 ID 之后开始读取；`"0"` 表示从头读取，
+    ///   `"$"` 表示只读取新消息（首次读取时会解析为当前最后一条消息的 ID）
+    ///
+    /// # Returns
+    ///
+    /// 返回 StreamReader 实例，默认每次最多读取 100 条、阻塞 5 秒
+    pub fn new(
+        connector: RedisConnector,
+        key: impl Into<String>,
+        start_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            connector,
+            conn: None,
+            key: key.into(),
+            last_id: start_id.into(),
+            count: 100,
+            block: Duration::from_secs(5),
+        }
+    }
+
+    /// 设置每次最多读取的消息数量
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// 设置每次 XREAD 的服务端阻塞时长
+    pub fn block(mut self, block: Duration) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// 获取流键名
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 获取最后读到的消息 ID，可持久化后用于下次创建读取器
+    pub fn last_id(&self) -> &str {
+        &self.last_id
+    }
+
+    /// 读取下一批消息
+    ///
+    /// # Returns
+    ///
+    /// 返回读取到的消息，阻塞超时内没有新消息时返回空列表
+    pub async fn read(&mut self) -> Result<Vec<StreamId>> {
+        let mut attempt = 0;
+        loop {
+            match self.try_read().await {
+                Err(e)
+                    if e.is_connection_error()
+                        && attempt < self.connector.pool_config.max_retries =>
+                {
+                    // 丢弃出错的连接，下次读取时重新建立
+                    self.conn = None;
+                    let delay = self.connector.pool_config.retry_delay(attempt);
+                    log::debug!(
+                        "Stream reader for {} lost connection, resuming after {} in {delay:?}: {e}",
+                        self.key,
+                        self.last_id
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) => {
+                    self.conn = None;
+                    return Err(e);
+                }
+                Ok(ids) => return Ok(ids),
+            }
+        }
+    }
+
+    /// 在当前连接上执行一次 XREAD，没有连接时先建立连接
+    async fn try_read(&mut self) -> Result<Vec<StreamId>> {
+        let conn = match &mut self.conn {
+            Some(conn) => conn,
+            None => {
+                let conn = self
+                    .connector
+                    .client()?
+                    .get_multiplexed_async_connection()
+                    .await?;
+                self.conn.insert(conn)
+            }
+        };
+
+        // "$" 只在单次 XREAD 中有意义，重连后再使用会跳过断线期间的消息，
+        // 因此首次读取前先解析为当前最后一条消息的 ID
+        if self.last_id == "$" {
+            let latest: StreamRangeReply = redis::cmd("XREVRANGE")
+                .arg(&self.key)
+                .arg("+")
+                .arg("-")
+                .arg("COUNT")
+                .arg(1)
+                .query_async(conn)
+                .await?;
+            self.last_id = latest
+                .ids
+                .first()
+                .map_or_else(|| "0-0".to_string(), |entry| entry.id.clone());
+        }
+
+        let reply: Option<StreamReadReply> = redis::cmd("XREAD")
+            .arg("COUNT")
+            .arg(self.count)
+            .arg("BLOCK")
+            .arg(self.block.as_millis() as u64)
+            .arg("STREAMS")
+            .arg(&self.key)
+            .arg(&self.last_id)
+            .query_async(conn)
+            .await?;
+
+        let ids: Vec<StreamId> = reply
+            .into_iter()
+            .flat_map(|reply| reply.keys)
+            .flat_map(|key| key.ids)
+            .collect();
+        if let Some(last) = ids.last() {
+            self.last_id = last.id.clone();
+        }
+        Ok(ids)
+    }
+}
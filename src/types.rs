@@ -0,0 +1,653 @@
+//! Redis 命令返回值类型
+
+use redis::{ErrorKind, FromRedisValue, RedisError, RedisResult, Value};
+
+/// 按模式统计的内存使用报告
+#[derive(Debug, Clone, Default)]
+pub struct MemoryReport {
+    /// 统计到的键占用的总字节数
+    pub total_bytes: u64,
+    /// 统计到的键数量
+    pub key_count: u64,
+    /// 占用内存最大的若干个键，按字节数降序排列
+    pub largest: Vec<(String, u64)>,
+    /// 是否因达到采样上限而提前结束扫描
+    pub sampled: bool,
+}
+
+/// 超过大小阈值的键
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigKey {
+    /// 键名
+    pub key: String,
+    /// 键的类型，例如 `string`、`hash`、`zset`
+    pub key_type: String,
+    /// MEMORY USAGE 报告的字节数
+    pub bytes: u64,
+    /// 元素数量（字符串为字节长度），未知类型时为 None
+    pub elements: Option<u64>,
+}
+
+/// 键的存在性、类型与过期时间
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyInfo {
+    /// 键是否存在
+    pub exists: bool,
+    /// 键的类型，例如 `string`、`hash`，键不存在时为 None
+    pub key_type: Option<String>,
+    /// 剩余秒数，-1表示永不过期，-2表示键不存在
+    pub ttl: i64,
+}
+
+/// LCS 命令 IDX 模式中的一段匹配
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LcsMatch {
+    /// 在第一个键中的起止位置（闭区间）
+    pub first: (i64, i64),
+    /// 在第二个键中的起止位置（闭区间）
+    pub second: (i64, i64),
+    /// 匹配长度
+    pub len: i64,
+}
+
+/// LCS 命令 IDX 模式的返回结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LcsIdx {
+    /// 所有匹配段，按 Redis 返回顺序（从后往前）排列
+    pub matches: Vec<LcsMatch>,
+    /// 最长公共子序列的长度
+    pub len: i64,
+}
+
+impl FromRedisValue for LcsIdx {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let mut result = LcsIdx::default();
+
+        for (name, value) in field_pairs(v)? {
+            match name.as_str() {
+                "matches" => {
+                    let items: Vec<Vec<Value>> = FromRedisValue::from_redis_value(value)?;
+                    for item in items {
+                        let first: (i64, i64) = match item.first() {
+                            Some(v) => FromRedisValue::from_redis_value(v)?,
+                            None => return Err(invalid_reply("LCS match is missing ranges")),
+                        };
+                        let second: (i64, i64) = match item.get(1) {
+                            Some(v) => FromRedisValue::from_redis_value(v)?,
+                            None => return Err(invalid_reply("LCS match is missing ranges")),
+                        };
+                        let len = match item.get(2) {
+                            Some(v) => FromRedisValue::from_redis_value(v)?,
+                            None => first.1 - first.0 + 1,
+                        };
+                        result.matches.push(LcsMatch { first, second, len });
+                    }
+                }
+                "len" => result.len = FromRedisValue::from_redis_value(value)?,
+                _ => {}
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// 将字段/值形式的回复（RESP2 的扁平数组或 RESP3 的 Map）拆分为字段对
+fn field_pairs(v: &Value) -> RedisResult<Vec<(String, &Value)>> {
+    match v {
+        Value::Map(pairs) => pairs
+            .iter()
+            .map(|(k, v)| Ok((String::from_redis_value(k)?, v)))
+            .collect(),
+        Value::Array(items) => {
+            if !items.len().is_multiple_of(2) {
+                return Err(invalid_reply("field/value reply has odd length"));
+            }
+            items
+                .chunks(2)
+                .map(|pair| Ok((String::from_redis_value(&pair[0])?, &pair[1])))
+                .collect()
+        }
+        _ => Err(invalid_reply("expected a field/value reply")),
+    }
+}
+
+/// 构造回复格式不符合预期的错误
+fn invalid_reply(detail: &'static str) -> RedisError {
+    RedisError::from((ErrorKind::TypeError, "Invalid reply", detail.to_string()))
+}
+
+/// CLIENT LIST 中的一个客户端连接
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientInfo {
+    /// 客户端 ID
+    pub id: u64,
+    /// 客户端地址（ip:port）
+    pub addr: String,
+    /// 客户端名称，未设置时为空
+    pub name: String,
+    /// 连接存在的时长（秒）
+    pub age: u64,
+    /// 连接空闲的时长（秒）
+    pub idle: u64,
+    /// 最近执行的命令
+    pub cmd: String,
+}
+
+impl ClientInfo {
+    /// 解析 CLIENT LIST 输出中的一行 `key=value` 记录
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - 以空格分隔的 `key=value` 字段
+    ///
+    /// # Returns
+    ///
+    /// 返回解析后的客户端信息，数值字段格式不正确时返回错误信息
+    pub fn parse_line(line: &str) -> std::result::Result<Self, String> {
+        let mut info = ClientInfo::default();
+
+        for field in line.split_whitespace() {
+            let Some((key, value)) = field.split_once('=') else {
+                continue;
+            };
+            let parse_u64 = |value: &str| {
+                value
+                    .parse::<u64>()
+                    .map_err(|e| format!("invalid value for `{key}`: {e}"))
+            };
+            match key {
+                "id" => info.id = parse_u64(value)?,
+                "addr" => info.addr = value.to_string(),
+                "name" => info.name = value.to_string(),
+                "age" => info.age = parse_u64(value)?,
+                "idle" => info.idle = parse_u64(value)?,
+                "cmd" => info.cmd = value.to_string(),
+                _ => {}
+            }
+        }
+
+        Ok(info)
+    }
+}
+
+/// CLUSTER NODES 中的一个节点
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClusterNode {
+    /// 节点 ID
+    pub id: String,
+    /// 节点地址（ip:port），不包含集群总线端口
+    pub addr: String,
+    /// 节点标志，如 myself、master、slave、fail
+    pub flags: Vec<String>,
+    /// 副本节点对应的主节点 ID，主节点为 None
+    pub master: Option<String>,
+    /// 连接状态，connected 或 disconnected
+    pub link_state: String,
+    /// 负责的槽位区间（闭区间）
+    pub slots: Vec<(u16, u16)>,
+}
+
+impl ClusterNode {
+    /// 解析 CLUSTER NODES 输出中的一行
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - 节点描述行
+    ///
+    /// # Returns
+    ///
+    /// 返回解析后的节点信息，字段缺失或槽位格式不正确时返回错误信息
+    pub fn parse_line(line: &str) -> std::result::Result<Self, String> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 8 {
+            return Err(format!("invalid cluster node line: {line}"));
+        }
+
+        // 地址格式为 ip:port@cport[,hostname]
+        let addr = fields[1]
+            .split(['@', ','])
+            .next()
+            .unwrap_or_default()
+            .to_string();
+
+        let mut slots = Vec::new();
+        for slot in &fields[8..] {
+            // 正在迁移中的槽位形如 [slot->-node-id]，不计入负责的槽位
+            if slot.starts_with('[') {
+                continue;
+            }
+            let parse = |s: &str| {
+                s.parse::<u16>()
+                    .map_err(|e| format!("invalid slot `{slot}`: {e}"))
+            };
+            let range = match slot.split_once('-') {
+                Some((start, end)) => (parse(start)?, parse(end)?),
+                None => {
+                    let single = parse(slot)?;
+                    (single, single)
+                }
+            };
+            slots.push(range);
+        }
+
+        Ok(ClusterNode {
+            id: fields[0].to_string(),
+            addr,
+            flags: fields[2].split(',').map(str::to_string).collect(),
+            master: (fields[3] != "-").then(|| fields[3].to_string()),
+            link_state: fields[7].to_string(),
+            slots,
+        })
+    }
+}
+
+/// SET 命令的写入条件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetCondition {
+    /// 仅当键不存在时写入（NX）
+    NotExists,
+    /// 仅当键已存在时写入（XX）
+    Exists,
+}
+
+/// SET 命令的过期设置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetTtl {
+    /// 过期时间（秒）（EX）
+    Seconds(u64),
+    /// 过期时间（毫秒）（PX）
+    Millis(u64),
+    /// 保留键原有的过期时间（KEEPTTL）
+    Keep,
+}
+
+/// SET 命令的完整选项
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SetOptions {
+    /// 写入条件，为 None 时总是写入
+    pub condition: Option<SetCondition>,
+    /// 过期设置，为 None 时清除原有的过期时间
+    pub ttl: Option<SetTtl>,
+    /// 是否返回键的旧值（GET）
+    pub get: bool,
+}
+
+impl SetOptions {
+    /// 仅当键不存在时写入
+    pub fn nx(mut self) -> Self {
+        self.condition = Some(SetCondition::NotExists);
+        self
+    }
+
+    /// 仅当键已存在时写入
+    pub fn xx(mut self) -> Self {
+        self.condition = Some(SetCondition::Exists);
+        self
+    }
+
+    /// 设置过期时间（秒）
+    pub fn ex(mut self, seconds: u64) -> Self {
+        self.ttl = Some(SetTtl::Seconds(seconds));
+        self
+    }
+
+    /// 设置过期时间（毫秒）
+    pub fn px(mut self, millis: u64) -> Self {
+        self.ttl = Some(SetTtl::Millis(millis));
+        self
+    }
+
+    /// 保留键原有的过期时间
+    pub fn keep_ttl(mut self) -> Self {
+        self.ttl = Some(SetTtl::Keep);
+        self
+    }
+
+    /// 同时返回键的旧值
+    pub fn get(mut self) -> Self {
+        self.get = true;
+        self
+    }
+}
+
+/// SET 命令的执行结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SetResponse {
+    /// 是否实际写入了新值
+    pub written: bool,
+    /// 键的旧值，仅在启用 GET 选项时返回
+    pub previous: Option<String>,
+}
+
+/// ZADD 命令的写入条件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZAddCondition {
+    /// 只添加新成员，不更新已有成员（NX）
+    NotExists,
+    /// 只更新已有成员，不添加新成员（XX）
+    Exists,
+}
+
+/// ZADD 命令更新已有成员时的分数比较条件（Redis 6.2+）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZAddComparison {
+    /// 新分数大于当前分数时才更新（GT）
+    GreaterThan,
+    /// 新分数小于当前分数时才更新（LT）
+    LessThan,
+}
+
+/// ZADD 命令的完整选项
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ZAddOptions {
+    /// 写入条件，为 None 时添加新成员并更新已有成员
+    pub condition: Option<ZAddCondition>,
+    /// 分数比较条件，不能与 NX 同时使用
+    pub comparison: Option<ZAddComparison>,
+    /// 返回值是否包含分数被修改的已有成员（CH）
+    pub ch: bool,
+}
+
+impl ZAddOptions {
+    /// 只添加新成员
+    pub fn nx(mut self) -> Self {
+        self.condition = Some(ZAddCondition::NotExists);
+        self
+    }
+
+    /// 只更新已有成员
+    pub fn xx(mut self) -> Self {
+        self.condition = Some(ZAddCondition::Exists);
+        self
+    }
+
+    /// 新分数更大时才更新
+    pub fn gt(mut self) -> Self {
+        self.comparison = Some(ZAddComparison::GreaterThan);
+        self
+    }
+
+    /// 新分数更小时才更新
+    pub fn lt(mut self) -> Self {
+        self.comparison = Some(ZAddComparison::LessThan);
+        self
+    }
+
+    /// 返回新增与分数被修改的成员总数
+    pub fn ch(mut self) -> Self {
+        self.ch = true;
+        self
+    }
+}
+
+/// 列表的推入方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum End {
+    /// 从左侧（头部）推入（LPUSH）
+    Left,
+    /// 从右侧（尾部）推入（RPUSH）
+    Right,
+}
+
+/// LATENCY LATEST 中的一个延迟事件
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LatencyEvent {
+    /// 事件名称，例如 `command`、`fast-command`
+    pub event: String,
+    /// 最近一次延迟尖峰发生的时间（Unix 时间戳，秒）
+    pub timestamp: i64,
+    /// 最近一次延迟尖峰的耗时（毫秒）
+    pub latest_ms: i64,
+    /// 该事件记录到的最大耗时（毫秒）
+    pub max_ms: i64,
+}
+
+impl FromRedisValue for LatencyEvent {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let items: Vec<Value> = FromRedisValue::from_redis_value(v)?;
+        if items.len() < 4 {
+            return Err(invalid_reply("LATENCY LATEST entry has too few fields"));
+        }
+
+        Ok(LatencyEvent {
+            event: FromRedisValue::from_redis_value(&items[0])?,
+            timestamp: FromRedisValue::from_redis_value(&items[1])?,
+            latest_ms: FromRedisValue::from_redis_value(&items[2])?,
+            max_ms: FromRedisValue::from_redis_value(&items[3])?,
+        })
+    }
+}
+
+/// ACL GETUSER 返回的用户权限信息
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AclUser {
+    /// 用户标志，例如 `on`、`nopass`、`allkeys`
+    pub flags: Vec<String>,
+    /// 密码的 SHA-256 摘要
+    pub passwords: Vec<String>,
+    /// 命令规则，例如 `+@all -debug`
+    pub commands: String,
+    /// 键模式，例如 `~cache:*`
+    pub keys: Vec<String>,
+    /// 发布订阅频道模式，例如 `&*`
+    pub channels: Vec<String>,
+}
+
+impl FromRedisValue for AclUser {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let mut user = AclUser::default();
+
+        for (name, value) in field_pairs(v)? {
+            match name.as_str() {
+                "flags" => user.flags = FromRedisValue::from_redis_value(value)?,
+                "passwords" => user.passwords = FromRedisValue::from_redis_value(value)?,
+                "commands" => user.commands = FromRedisValue::from_redis_value(value)?,
+                "keys" => user.keys = acl_patterns(value)?,
+                "channels" => user.channels = acl_patterns(value)?,
+                _ => {}
+            }
+        }
+
+        Ok(user)
+    }
+}
+
+/// 解析 ACL 模式字段：Redis 7 返回以空格分隔的字符串，Redis 6 返回数组
+fn acl_patterns(v: &Value) -> RedisResult<Vec<String>> {
+    match v {
+        Value::Array(_) | Value::Set(_) => FromRedisValue::from_redis_value(v),
+        _ => {
+            let patterns: String = FromRedisValue::from_redis_value(v)?;
+            Ok(patterns.split_whitespace().map(str::to_string).collect())
+        }
+    }
+}
+
+/// XINFO STREAM 返回的流信息
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StreamInfo {
+    /// 流中的消息数量
+    pub length: u64,
+    /// 底层基数树的键数量
+    pub radix_tree_keys: u64,
+    /// 底层基数树的节点数量
+    pub radix_tree_nodes: u64,
+    /// 消费者组数量
+    pub groups: u64,
+    /// 最后生成的消息 ID
+    pub last_generated_id: String,
+    /// 流创建以来写入的消息总数（Redis 7.0+），更早的版本为 None
+    pub entries_added: Option<u64>,
+    /// 第一条消息的 ID，流为空时为 None
+    pub first_entry_id: Option<String>,
+    /// 最后一条消息的 ID，流为空时为 None
+    pub last_entry_id: Option<String>,
+}
+
+impl FromRedisValue for StreamInfo {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let mut info = StreamInfo::default();
+
+        for (name, value) in field_pairs(v)? {
+            match name.as_str() {
+                "length" => info.length = FromRedisValue::from_redis_value(value)?,
+                "radix-tree-keys" => {
+                    info.radix_tree_keys = FromRedisValue::from_redis_value(value)?
+                }
+                "radix-tree-nodes" => {
+                    info.radix_tree_nodes = FromRedisValue::from_redis_value(value)?
+                }
+                "groups" => info.groups = FromRedisValue::from_redis_value(value)?,
+                "last-generated-id" => {
+                    info.last_generated_id = FromRedisValue::from_redis_value(value)?
+                }
+                "entries-added" => info.entries_added = FromRedisValue::from_redis_value(value)?,
+                "first-entry" => info.first_entry_id = stream_entry_id(value)?,
+                "last-entry" => info.last_entry_id = stream_entry_id(value)?,
+                _ => {}
+            }
+        }
+
+        Ok(info)
+    }
+}
+
+/// 从 `[id, [field, value, ...]]` 形式的消息中取出 ID，空流时为 nil
+fn stream_entry_id(v: &Value) -> RedisResult<Option<String>> {
+    match v {
+        Value::Nil => Ok(None),
+        Value::Array(items) => match items.first() {
+            Some(id) => Ok(Some(FromRedisValue::from_redis_value(id)?)),
+            None => Ok(None),
+        },
+        _ => Err(invalid_reply("expected a stream entry")),
+    }
+}
+
+/// XINFO GROUPS 返回的消费者组信息
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GroupInfo {
+    /// 消费者组名
+    pub name: String,
+    /// 消费者数量
+    pub consumers: u64,
+    /// 已投递但尚未确认的消息数量
+    pub pending: u64,
+    /// 最后投递给该组的消息 ID
+    pub last_delivered_id: String,
+    /// 该组已读取的消息数量（Redis 7.0+）
+    pub entries_read: Option<u64>,
+    /// 尚未投递给该组的消息数量（Redis 7.0+），无法计算时为 None
+    pub lag: Option<u64>,
+}
+
+impl FromRedisValue for GroupInfo {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let mut group = GroupInfo::default();
+
+        for (name, value) in field_pairs(v)? {
+            match name.as_str() {
+                "name" => group.name = FromRedisValue::from_redis_value(value)?,
+                "consumers" => group.consumers = FromRedisValue::from_redis_value(value)?,
+                "pending" => group.pending = FromRedisValue::from_redis_value(value)?,
+                "last-delivered-id" => {
+                    group.last_delivered_id = FromRedisValue::from_redis_value(value)?
+                }
+                "entries-read" => group.entries_read = FromRedisValue::from_redis_value(value)?,
+                "lag" => group.lag = FromRedisValue::from_redis_value(value)?,
+                _ => {}
+            }
+        }
+
+        Ok(group)
+    }
+}
+
+/// 有序集合并集/交集运算中同一成员分数的合并方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Aggregate {
+    /// 分数求和（默认）
+    #[default]
+    Sum,
+    /// 取最小分数
+    Min,
+    /// 取最大分数
+    Max,
+}
+
+impl Aggregate {
+    /// 获取 AGGREGATE 参数对应的名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Aggregate::Sum => "SUM",
+            Aggregate::Min => "MIN",
+            Aggregate::Max => "MAX",
+        }
+    }
+}
+
+/// CLIENT PAUSE 的暂停范围
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PauseMode {
+    /// 暂停所有客户端命令
+    #[default]
+    All,
+    /// 只暂停写命令（Redis 6.2+）
+    Write,
+}
+
+/// Redis 服务器版本，可直接比较大小
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    /// 主版本号
+    pub major: u32,
+    /// 次版本号
+    pub minor: u32,
+    /// 修订号
+    pub patch: u32,
+}
+
+impl Version {
+    /// 创建版本号
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// 解析 `major.minor.patch` 格式的版本号，缺失的部分视为 0
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - 版本号字符串，例如 `7.2.4`
+    ///
+    /// # Returns
+    ///
+    /// 返回解析后的版本号，格式不正确时返回错误信息
+    pub fn parse(s: &str) -> std::result::Result<Self, String> {
+        let mut parts = s.trim().splitn(3, '.');
+        let mut next = |name: &str| -> std::result::Result<u32, String> {
+            match parts.next() {
+                Some(part) => part
+                    .parse()
+                    .map_err(|e| format!("invalid {name} version in `{s}`: {e}")),
+                None => Ok(0),
+            }
+        };
+
+        Ok(Self {
+            major: next("major")?,
+            minor: next("minor")?,
+            patch: next("patch")?,
+        })
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
@@ -1,7 +1,27 @@
+use crate::bitfield::BitField;
+use crate::deadline::Deadline;
+#[cfg(feature = "encryption")]
+use crate::encryption::EncryptionKey;
 use crate::error::{ConnectionError, Result};
+use crate::limit::SizeLimit;
+use crate::pool::PoolConfig;
+use crate::types::{
+    AclUser, Aggregate, BigKey, ClientInfo, ClusterNode, End, GroupInfo, KeyInfo, LatencyEvent,
+    LcsIdx, MemoryReport, PauseMode, SetCondition, SetOptions, SetResponse, SetTtl, StreamInfo,
+    Version, ZAddComparison, ZAddCondition, ZAddOptions,
+};
+use futures_util::Stream;
+use futures_util::future::BoxFuture;
 use redis::aio::ConnectionManager;
-use redis::{AsyncCommands, FromRedisValue, ToRedisArgs};
+use redis::streams::{StreamAutoClaimOptions, StreamAutoClaimReply, StreamPendingReply};
+use redis::{AsyncCommands, FromRedisValue, SetExpiry, ToRedisArgs};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::time::Duration;
 
 /// Redis 工具类 - 提供常用的异步 Redis 操作方法
 pub struct RedisUtils;
@@ -53,77 +73,185 @@ impl RedisUtils {
         Ok(())
     }
 
-    /// 获取字符串值
+    /// 设置字符串值并指定毫秒级过期时间
     ///
     /// # Arguments
     ///
     /// * `key` - 键名
+    /// * `value` - 值
+    /// * `millis` - 过期时间（毫秒），不能为 0
     ///
     /// # Returns
     ///
-    /// 返回值或 None
-    pub async fn get<K, V>(conn: &mut ConnectionManager, key: K) -> Result<Option<V>>
+    /// 返回操作结果
+    pub async fn psetex<K, V>(
+        conn: &mut ConnectionManager,
+        key: K,
+        value: V,
+        millis: u64,
+    ) -> Result<()>
     where
         K: ToRedisArgs + Send + Sync,
-        V: FromRedisValue,
+        V: ToRedisArgs + Send + Sync,
     {
-        let result: Option<V> = conn.get(key).await?;
-        Ok(result)
+        if millis == 0 {
+            return Err(ConnectionError::Configuration(
+                "PSETEX expiry must be greater than 0 milliseconds".to_string(),
+            ));
+        }
+
+        let _: () = conn.pset_ex(key, value, millis).await?;
+        Ok(())
     }
 
-    /// 删除键
+    /// 设置字符串值并以 Duration 指定过期时间
+    ///
+    /// 过期时间为整秒时使用 `SET ... EX`，否则使用 `SET ... PX` 以保留毫秒精度。
     ///
     /// # Arguments
     ///
-    /// * `keys` - 要删除的键名列表
+    /// * `key` - 键名
+    /// * `value` - 值
+    /// * `ttl` - 过期时间，不能小于 1 毫秒
     ///
     /// # Returns
     ///
-    /// 返回删除的键数量
-    pub async fn del<K>(conn: &mut ConnectionManager, keys: K) -> Result<i32>
+    /// 返回操作结果
+    pub async fn set_duration<K, V>(
+        conn: &mut ConnectionManager,
+        key: K,
+        value: V,
+        ttl: Duration,
+    ) -> Result<()>
     where
         K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
     {
-        let result: i32 = conn.del(keys).await?;
-        Ok(result)
+        let options = redis::SetOptions::default().with_expiration(Self::set_expiry(ttl)?);
+        let _: () = conn.set_options(key, value, options).await?;
+        Ok(())
     }
 
-    /// 检查键是否存在
+    /// 按完整选项执行 SET 命令
+    ///
+    /// 在一次调用中组合 EX/PX/KEEPTTL 过期设置、NX/XX 写入条件及 GET 旧值返回。
     ///
     /// # Arguments
     ///
     /// * `key` - 键名
+    /// * `value` - 值
+    /// * `options` - SET 选项
     ///
     /// # Returns
     ///
-    /// 返回是否存在
-    pub async fn exists<K>(conn: &mut ConnectionManager, key: K) -> Result<bool>
+    /// 返回是否写入以及（启用 GET 时）键的旧值
+    pub async fn set_opts<K, V>(
+        conn: &mut ConnectionManager,
+        key: K,
+        value: V,
+        options: SetOptions,
+    ) -> Result<SetResponse>
     where
         K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
     {
-        let result: bool = conn.exists(key).await?;
-        Ok(result)
+        let mut cmd = redis::cmd("SET");
+        cmd.arg(key).arg(value);
+        match options.condition {
+            Some(SetCondition::NotExists) => {
+                cmd.arg("NX");
+            }
+            Some(SetCondition::Exists) => {
+                cmd.arg("XX");
+            }
+            None => {}
+        }
+        if options.get {
+            cmd.arg("GET");
+        }
+        match options.ttl {
+            Some(SetTtl::Seconds(seconds)) => {
+                cmd.arg("EX").arg(seconds);
+            }
+            Some(SetTtl::Millis(millis)) => {
+                cmd.arg("PX").arg(millis);
+            }
+            Some(SetTtl::Keep) => {
+                cmd.arg("KEEPTTL");
+            }
+            None => {}
+        }
+
+        let reply: redis::Value = cmd.query_async(conn).await?;
+
+        if !options.get {
+            // 未启用 GET 时，写入成功返回 OK，条件不满足返回 nil
+            return Ok(SetResponse {
+                written: reply != redis::Value::Nil,
+                previous: None,
+            });
+        }
+
+        // 启用 GET 时返回旧值，是否写入需要结合写入条件判断
+        let previous: Option<String> = FromRedisValue::from_redis_value(&reply)?;
+        let written = match options.condition {
+            Some(SetCondition::NotExists) => previous.is_none(),
+            Some(SetCondition::Exists) => previous.is_some(),
+            None => true,
+        };
+
+        Ok(SetResponse { written, previous })
     }
 
-    /// 设置键的过期时间
+    /// 仅当键不存在时写入，并返回本次是否为首次写入（幂等键）
+    ///
+    /// 基于 SET NX 实现，写入与过期设置在同一条命令中完成。
     ///
     /// # Arguments
     ///
     /// * `key` - 键名
-    /// * `seconds` - 过期时间（秒）
+    /// * `value` - 值
+    /// * `ttl` - 可选的过期时间，不能小于 1 毫秒
     ///
     /// # Returns
     ///
-    /// 返回操作结果
-    pub async fn expire<K>(conn: &mut ConnectionManager, key: K, seconds: usize) -> Result<bool>
+    /// 键此前不存在并已写入时返回 true，键已存在时返回 false
+    pub async fn set_if_absent<K, V>(
+        conn: &mut ConnectionManager,
+        key: K,
+        value: V,
+        ttl: Option<Duration>,
+    ) -> Result<bool>
     where
         K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
     {
-        let result: bool = conn.expire(key, seconds as i64).await?;
-        Ok(result)
+        let mut options = redis::SetOptions::default().conditional_set(redis::ExistenceCheck::NX);
+        if let Some(ttl) = ttl {
+            options = options.with_expiration(Self::set_expiry(ttl)?);
+        }
+
+        // 写入成功返回 OK，键已存在时返回 nil
+        let reply: Option<String> = conn.set_options(key, value, options).await?;
+        Ok(reply.is_some())
     }
 
-    /// 获取键的剩余生存时间
+    /// 将 Duration 转换为 SET 命令的过期参数
+    pub(crate) fn set_expiry(ttl: Duration) -> Result<SetExpiry> {
+        if ttl.as_millis() == 0 {
+            return Err(ConnectionError::Configuration(
+                "TTL must be at least 1 millisecond".to_string(),
+            ));
+        }
+
+        if ttl.subsec_nanos() == 0 {
+            Ok(SetExpiry::EX(ttl.as_secs()))
+        } else {
+            Ok(SetExpiry::PX(ttl.as_millis() as u64))
+        }
+    }
+
+    /// 获取字符串值
     ///
     /// # Arguments
     ///
@@ -131,632 +259,4085 @@ impl RedisUtils {
     ///
     /// # Returns
     ///
-    /// 返回剩余秒数，-1表示永不过期，-2表示键不存在
-    pub async fn ttl<K>(conn: &mut ConnectionManager, key: K) -> Result<i32>
+    /// 返回值或 None
+    pub async fn get<K, V>(conn: &mut ConnectionManager, key: K) -> Result<Option<V>>
     where
         K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
     {
-        let result: i32 = conn.ttl(key).await?;
+        let result: Option<V> = conn.get(key).await?;
         Ok(result)
     }
 
-    // ==================== 哈希表操作 ====================
-
-    /// 设置哈希表字段值
+    /// 获取字符串值并删除该键
+    ///
+    /// 优先使用 GETDEL（Redis 6.2+），服务器不支持时退化为原子执行的 Lua 脚本。
     ///
     /// # Arguments
     ///
-    /// * `key` - 哈希表键名
-    /// * `field` - 字段名
-    /// * `value` - 值
+    /// * `key` - 键名
     ///
     /// # Returns
     ///
-    /// 返回操作结果
-    pub async fn hset<K, F, V>(
-        conn: &mut ConnectionManager,
-        key: K,
-        field: F,
-        value: V,
-    ) -> Result<bool>
+    /// 返回删除前的值，键不存在时返回 None
+    pub async fn getdel<K, V>(conn: &mut ConnectionManager, key: K) -> Result<Option<V>>
     where
         K: ToRedisArgs + Send + Sync,
-        F: ToRedisArgs + Send + Sync,
-        V: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
     {
-        let result: bool = conn.hset(key, field, value).await?;
-        Ok(result)
+        let native: redis::RedisResult<Option<V>> =
+            redis::cmd("GETDEL").arg(&key).query_async(conn).await;
+        match native {
+            Ok(value) => return Ok(value),
+            Err(e) => Self::check_native_error(e, "GETDEL")?,
+        }
+
+        let script = redis::Script::new(
+            r"
+                local value = redis.call('GET', KEYS[1])
+                if value then
+                    redis.call('DEL', KEYS[1])
+                end
+                return value
+                ",
+        );
+        let value: Option<V> = script.key(&key).invoke_async(conn).await?;
+        Ok(value)
     }
 
-    /// 获取哈希表字段值
+    /// 获取字符串值并同时修改其过期时间
+    ///
+    /// 优先使用 GETEX（Redis 6.2+），服务器不支持时退化为原子执行的 Lua 脚本。
     ///
     /// # Arguments
     ///
-    /// * `key` - 哈希表键名
-    /// * `field` - 字段名
+    /// * `key` - 键名
+    /// * `ttl` - 新的过期时间，不能小于 1 毫秒；为 None 时移除过期时间（PERSIST）
     ///
     /// # Returns
     ///
-    /// 返回字段值或 None
-    pub async fn hget<K, F, V>(conn: &mut ConnectionManager, key: K, field: F) -> Result<Option<V>>
+    /// 返回值，键不存在时返回 None
+    pub async fn getex<K, V>(
+        conn: &mut ConnectionManager,
+        key: K,
+        ttl: Option<Duration>,
+    ) -> Result<Option<V>>
     where
         K: ToRedisArgs + Send + Sync,
-        F: ToRedisArgs + Send + Sync,
         V: FromRedisValue,
     {
-        let result: Option<V> = conn.hget(key, field).await?;
-        Ok(result)
+        let millis = match ttl {
+            Some(ttl) if ttl.as_millis() == 0 => {
+                return Err(ConnectionError::Configuration(
+                    "TTL must be at least 1 millisecond".to_string(),
+                ));
+            }
+            Some(ttl) => Some(ttl.as_millis() as u64),
+            None => None,
+        };
+
+        let mut cmd = redis::cmd("GETEX");
+        cmd.arg(&key);
+        match millis {
+            Some(millis) => cmd.arg("PX").arg(millis),
+            None => cmd.arg("PERSIST"),
+        };
+
+        let native: redis::RedisResult<Option<V>> = cmd.query_async(conn).await;
+        match native {
+            Ok(value) => return Ok(value),
+            Err(e) => Self::check_native_error(e, "GETEX")?,
+        }
+
+        let script = redis::Script::new(
+            r"
+                local value = redis.call('GET', KEYS[1])
+                if value then
+                    if ARGV[1] then
+                        redis.call('PEXPIRE', KEYS[1], ARGV[1])
+                    else
+                        redis.call('PERSIST', KEYS[1])
+                    end
+                end
+                return value
+                ",
+        );
+        let mut invocation = script.key(&key);
+        if let Some(millis) = millis {
+            invocation.arg(millis);
+        }
+        let value: Option<V> = invocation.invoke_async(conn).await?;
+        Ok(value)
     }
 
-    /// 获取哈希表所有字段和值
+    /// 原子地读取并删除一次性令牌（Redis 6.2+）
+    ///
+    /// 基于 GETDEL 实现，即使并发请求同时兑换，同一令牌也只会被其中一个请求取到。
     ///
     /// # Arguments
     ///
-    /// * `key` - 哈希表键名
+    /// * `key` - 令牌键名
     ///
     /// # Returns
     ///
-    /// 返回字段值映射
-    pub async fn hgetall<K>(
-        conn: &mut ConnectionManager,
-        key: K,
-    ) -> Result<std::collections::HashMap<String, String>>
+    /// 返回令牌内容，令牌已被使用或已过期时返回 None
+    pub async fn consume_token<K>(conn: &mut ConnectionManager, key: K) -> Result<Option<String>>
     where
         K: ToRedisArgs + Send + Sync,
     {
-        let result: std::collections::HashMap<String, String> = conn.hgetall(key).await?;
+        let result: Option<String> = conn.get_del(key).await?;
         Ok(result)
     }
 
-    /// 删除哈希表字段
+    /// 以浮点数增量递增键的值（INCRBYFLOAT）
+    ///
+    /// 键不存在时视为 0。Redis 内部以 long double 计算并以最多 17 位有效数字的十进制字符串返回，
+    /// 结果解析为 `f64` 后可能与服务器保存的值存在末位误差；二进制浮点数无法精确表示大多数十进制小数，
+    /// 对精度敏感的金额建议以最小货币单位存储为整数。
     ///
     /// # Arguments
     ///
-    /// * `key` - 哈希表键名
-    /// * `fields` - 要删除的字段名列表
+    /// * `key` - 键名
+    /// * `delta` - 增量，可以为负数，必须为有限数
     ///
     /// # Returns
     ///
-    /// 返回删除的字段数量
-    pub async fn hdel<K, F>(conn: &mut ConnectionManager, key: K, fields: F) -> Result<i32>
+    /// 返回递增后的值，键的当前值不是合法浮点数时返回反序列化错误
+    pub async fn incr_by_float<K>(conn: &mut ConnectionManager, key: K, delta: f64) -> Result<f64>
     where
         K: ToRedisArgs + Send + Sync,
-        F: ToRedisArgs + Send + Sync,
     {
-        let result: i32 = conn.hdel(key, fields).await?;
-        Ok(result)
+        if !delta.is_finite() {
+            return Err(ConnectionError::Configuration(format!(
+                "INCRBYFLOAT increment must be finite, got {delta}"
+            )));
+        }
+
+        let reply: std::result::Result<String, redis::RedisError> = redis::cmd("INCRBYFLOAT")
+            .arg(key)
+            .arg(delta)
+            .query_async(conn)
+            .await;
+        let reply = match reply {
+            Ok(reply) => reply,
+            Err(e)
+                if e.kind() == redis::ErrorKind::ResponseError
+                    && e.detail()
+                        .is_some_and(|detail| detail.contains("not a valid float")) =>
+            {
+                return Err(ConnectionError::Deserialization(e.to_string()));
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        reply.trim().parse::<f64>().map_err(|e| {
+            ConnectionError::Deserialization(format!("invalid INCRBYFLOAT reply `{reply}`: {e}"))
+        })
     }
 
-    /// 检查哈希表字段是否存在
+    /// 删除键
     ///
     /// # Arguments
     ///
-    /// * `key` - 哈希表键名
-    /// * `field` - 字段名
+    /// * `keys` - 要删除的键名列表
     ///
     /// # Returns
     ///
-    /// 返回是否存在
-    pub async fn hexists<K, F>(conn: &mut ConnectionManager, key: K, field: F) -> Result<bool>
+    /// 返回删除的键数量
+    pub async fn del<K>(conn: &mut ConnectionManager, keys: K) -> Result<i32>
     where
         K: ToRedisArgs + Send + Sync,
-        F: ToRedisArgs + Send + Sync,
     {
-        let result: bool = conn.hexists(key, field).await?;
+        let result: i32 = conn.del(keys).await?;
         Ok(result)
     }
 
-    // ==================== 列表操作 ====================
-
-    /// 向列表左侧推入元素
+    /// 检查键是否存在
     ///
     /// # Arguments
     ///
-    /// * `key` - 列表键名
-    /// * `values` - 要推入的值
+    /// * `key` - 键名
     ///
     /// # Returns
     ///
-    /// 返回列表长度
-    pub async fn lpush<K, V>(conn: &mut ConnectionManager, key: K, values: V) -> Result<i32>
+    /// 返回是否存在
+    pub async fn exists<K>(conn: &mut ConnectionManager, key: K) -> Result<bool>
     where
         K: ToRedisArgs + Send + Sync,
-        V: ToRedisArgs + Send + Sync,
     {
-        let result: i32 = conn.lpush(key, values).await?;
+        let result: bool = conn.exists(key).await?;
         Ok(result)
     }
 
-    /// 向列表右侧推入元素
+    /// 将键移动到同一实例的另一个数据库
     ///
     /// # Arguments
     ///
-    /// * `key` - 列表键名
-    /// * `values` - 要推入的值
+    /// * `key` - 键名
+    /// * `db` - 目标数据库
     ///
     /// # Returns
     ///
-    /// 返回列表长度
-    pub async fn rpush<K, V>(conn: &mut ConnectionManager, key: K, values: V) -> Result<i32>
+    /// 返回是否移动成功，源键不存在或目标数据库已存在同名键时返回 false
+    pub async fn move_key<K>(conn: &mut ConnectionManager, key: K, db: u8) -> Result<bool>
     where
         K: ToRedisArgs + Send + Sync,
-        V: ToRedisArgs + Send + Sync,
     {
-        let result: i32 = conn.rpush(key, values).await?;
+        let result: bool = redis::cmd("MOVE")
+            .arg(key)
+            .arg(db)
+            .query_async(conn)
+            .await?;
         Ok(result)
     }
 
-    /// 从列表左侧弹出元素
+    /// 将键的值复制到另一个键
+    ///
+    /// 优先使用 COPY（Redis 6.2+），服务器不支持时退化为基于 DUMP/RESTORE 的 Lua 脚本，
+    /// 两种方式都会保留剩余的过期时间。
     ///
     /// # Arguments
     ///
-    /// * `key` - 列表键名
+    /// * `source` - 源键名
+    /// * `destination` - 目标键名
+    /// * `replace` - 目标键已存在时是否覆盖
     ///
     /// # Returns
     ///
-    /// 返回弹出的元素或 None
-    pub async fn lpop<K, V>(conn: &mut ConnectionManager, key: K) -> Result<Option<V>>
+    /// 返回是否复制成功，源键不存在或目标键已存在且不覆盖时返回 false
+    pub async fn copy<S, D>(
+        conn: &mut ConnectionManager,
+        source: S,
+        destination: D,
+        replace: bool,
+    ) -> Result<bool>
     where
-        K: ToRedisArgs + Send + Sync,
-        V: FromRedisValue,
+        S: ToRedisArgs + Send + Sync,
+        D: ToRedisArgs + Send + Sync,
     {
-        let result: Option<V> = conn.lpop(key, None).await?;
-        Ok(result)
+        let mut cmd = redis::cmd("COPY");
+        cmd.arg(&source).arg(&destination);
+        if replace {
+            cmd.arg("REPLACE");
+        }
+
+        let native: redis::RedisResult<bool> = cmd.query_async(conn).await;
+        match native {
+            Ok(copied) => return Ok(copied),
+            Err(e) => Self::check_native_error(e, "COPY")?,
+        }
+
+        let script = redis::Script::new(
+            r"
+                local replace = ARGV[1] == '1'
+                if not replace and redis.call('EXISTS', KEYS[2]) == 1 then
+                    return 0
+                end
+                local dump = redis.call('DUMP', KEYS[1])
+                if not dump then
+                    return 0
+                end
+                local ttl = redis.call('PTTL', KEYS[1])
+                if ttl < 0 then
+                    ttl = 0
+                end
+                if replace then
+                    redis.call('RESTORE', KEYS[2], ttl, dump, 'REPLACE')
+                else
+                    redis.call('RESTORE', KEYS[2], ttl, dump)
+                end
+                return 1
+                ",
+        );
+        let copied: bool = script
+            .key(&source)
+            .key(&destination)
+            .arg(if replace { 1 } else { 0 })
+            .invoke_async(conn)
+            .await?;
+        Ok(copied)
     }
 
-    /// 从列表右侧弹出元素
+    /// 用暂存键原子地替换线上键，用于缓存的整代切换
+    ///
+    /// 通过 RENAME 覆盖线上键，旧值随之删除；旧值较大时建议在服务器上开启
+    /// `lazyfree-lazy-server-del`，让其在后台线程释放。
     ///
     /// # Arguments
     ///
-    /// * `key` - 列表键名
+    /// * `staging` - 已构建完成的暂存键
+    /// * `live` - 线上键
     ///
     /// # Returns
     ///
-    /// 返回弹出的元素或 None
-    pub async fn rpop<K, V>(conn: &mut ConnectionManager, key: K) -> Result<Option<V>>
-    where
-        K: ToRedisArgs + Send + Sync,
-        V: FromRedisValue,
-    {
-        let result: Option<V> = conn.rpop(key, None).await?;
-        Ok(result)
+    /// 返回操作结果，暂存键不存在时返回配置错误且线上键保持不变
+    pub async fn swap_keys(conn: &mut ConnectionManager, staging: &str, live: &str) -> Result<()> {
+        let result: redis::RedisResult<()> = redis::cmd("RENAME")
+            .arg(staging)
+            .arg(live)
+            .query_async(conn)
+            .await;
+
+        match result {
+            Ok(()) => Ok(()),
+            Err(e)
+                if e.detail()
+                    .is_some_and(|detail| detail.contains("no such key")) =>
+            {
+                Err(ConnectionError::Configuration(format!(
+                    "Staging key {staging} does not exist"
+                )))
+            }
+            Err(e) => Err(e.into()),
+        }
     }
 
-    /// 获取列表长度
+    /// 用暂存前缀下的所有键原子地替换线上前缀下的键
+    ///
+    /// 先 SCAN 出两个前缀下的键，再在一个 MULTI/EXEC 事务中把每个暂存键 RENAME
+    /// 到对应的线上键，并删除线上前缀中新一代不再包含的键。扫描与事务之间新写入
+    /// 暂存前缀的键不会被切换，调用前应确保新一代已构建完成。
     ///
     /// # Arguments
     ///
-    /// * `key` - 列表键名
+    /// * `staging_prefix` - 暂存键前缀，例如 `cache:v2:`
+    /// * `live_prefix` - 线上键前缀，例如 `cache:live:`
     ///
     /// # Returns
     ///
-    /// 返回列表长度
-    pub async fn llen<K>(conn: &mut ConnectionManager, key: K) -> Result<i32>
-    where
-        K: ToRedisArgs + Send + Sync,
-    {
-        let result: i32 = conn.llen(key).await?;
-        Ok(result)
+    /// 返回切换的键数量，暂存前缀下没有任何键时返回配置错误且线上键保持不变
+    pub async fn swap_prefix(
+        conn: &mut ConnectionManager,
+        staging_prefix: &str,
+        live_prefix: &str,
+    ) -> Result<usize> {
+        let staging_keys = Self::scan_prefix(conn, staging_prefix).await?;
+        if staging_keys.is_empty() {
+            return Err(ConnectionError::Configuration(format!(
+                "No staging keys found under prefix {staging_prefix}"
+            )));
+        }
+        let live_keys = Self::scan_prefix(conn, live_prefix).await?;
+
+        let suffixes: HashSet<&str> = staging_keys
+            .iter()
+            .map(|key| &key[staging_prefix.len()..])
+            .collect();
+
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        for suffix in &suffixes {
+            pipe.rename(
+                format!("{staging_prefix}{suffix}"),
+                format!("{live_prefix}{suffix}"),
+            )
+            .ignore();
+        }
+        for key in &live_keys {
+            if !suffixes.contains(&key[live_prefix.len()..]) {
+                pipe.del(key).ignore();
+            }
+        }
+        let _: () = pipe.query_async(conn).await?;
+
+        Ok(suffixes.len())
     }
 
-    /// 获取列表指定范围的元素
+    /// 扫描以指定前缀开头的所有键
+    async fn scan_prefix(conn: &mut ConnectionManager, prefix: &str) -> Result<Vec<String>> {
+        // 转义前缀中的 glob 特殊字符，保证按字面前缀匹配
+        let mut pattern = String::with_capacity(prefix.len() + 1);
+        for c in prefix.chars() {
+            if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+                pattern.push('\\');
+            }
+            pattern.push(c);
+        }
+        pattern.push('*');
+
+        let mut keys = Vec::new();
+        let mut cursor = 0;
+        loop {
+            let (next, batch) = Self::scan_batch(conn, cursor, &pattern, 100).await?;
+            keys.extend(batch);
+            if next == 0 {
+                break;
+            }
+            cursor = next;
+        }
+        Ok(keys)
+    }
+
+    /// 获取键对应值对象的引用计数
     ///
     /// # Arguments
     ///
-    /// * `key` - 列表键名
-    /// * `start` - 开始索引
-    /// * `stop` - 结束索引
+    /// * `key` - 键名
     ///
     /// # Returns
     ///
-    /// 返回元素列表
-    pub async fn lrange<K>(
-        conn: &mut ConnectionManager,
-        key: K,
-        start: isize,
-        stop: isize,
-    ) -> Result<Vec<String>>
+    /// 返回引用计数，键不存在时返回 None
+    pub async fn object_refcount<K>(conn: &mut ConnectionManager, key: K) -> Result<Option<i64>>
     where
         K: ToRedisArgs + Send + Sync,
     {
-        let result: Vec<String> = conn.lrange(key, start, stop).await?;
+        let result: Option<i64> = redis::cmd("OBJECT")
+            .arg("REFCOUNT")
+            .arg(key)
+            .query_async(conn)
+            .await?;
         Ok(result)
     }
 
-    // ==================== 集合操作 ====================
-
-    /// 向集合添加成员
+    /// 获取键对应值对象的内部编码
     ///
     /// # Arguments
     ///
-    /// * `key` - 集合键名
-    /// * `members` - 要添加的成员
+    /// * `key` - 键名
     ///
     /// # Returns
     ///
-    /// 返回添加的成员数量
-    pub async fn sadd<K, M>(conn: &mut ConnectionManager, key: K, members: M) -> Result<i32>
+    /// 返回编码名称（如 `listpack`、`quicklist`），键不存在时返回 None
+    pub async fn object_encoding<K>(conn: &mut ConnectionManager, key: K) -> Result<Option<String>>
     where
         K: ToRedisArgs + Send + Sync,
-        M: ToRedisArgs + Send + Sync,
     {
-        let result: i32 = conn.sadd(key, members).await?;
+        let result: Option<String> = redis::cmd("OBJECT")
+            .arg("ENCODING")
+            .arg(key)
+            .query_async(conn)
+            .await?;
         Ok(result)
     }
 
-    /// 从集合移除成员
+    /// 设置键的过期时间
     ///
     /// # Arguments
     ///
-    /// * `key` - 集合键名
-    /// * `members` - 要移除的成员
+    /// * `key` - 键名
+    /// * `seconds` - 过期时间（秒）
     ///
     /// # Returns
     ///
-    /// 返回移除的成员数量
-    pub async fn srem<K, M>(conn: &mut ConnectionManager, key: K, members: M) -> Result<i32>
+    /// 返回操作结果
+    pub async fn expire<K>(conn: &mut ConnectionManager, key: K, seconds: usize) -> Result<bool>
     where
         K: ToRedisArgs + Send + Sync,
-        M: ToRedisArgs + Send + Sync,
     {
-        let result: i32 = conn.srem(key, members).await?;
+        let result: bool = conn.expire(key, seconds as i64).await?;
         Ok(result)
     }
 
-    /// 检查成员是否在集合中
+    /// 获取键的剩余生存时间
     ///
     /// # Arguments
     ///
-    /// * `key` - 集合键名
-    /// * `member` - 成员
+    /// * `key` - 键名
     ///
     /// # Returns
     ///
-    /// 返回是否存在
-    pub async fn sismember<K, M>(conn: &mut ConnectionManager, key: K, member: M) -> Result<bool>
+    /// 返回剩余秒数，-1表示永不过期，-2表示键不存在
+    pub async fn ttl<K>(conn: &mut ConnectionManager, key: K) -> Result<i32>
     where
         K: ToRedisArgs + Send + Sync,
-        M: ToRedisArgs + Send + Sync,
     {
-        let result: bool = conn.sismember(key, member).await?;
+        let result: i32 = conn.ttl(key).await?;
         Ok(result)
     }
 
-    /// 获取集合所有成员
+    /// 批量获取多个键的剩余生存时间
+    ///
+    /// 所有 TTL 命令通过一次流水线发送，结果与输入键的顺序一一对应。
     ///
     /// # Arguments
     ///
-    /// * `key` - 集合键名
+    /// * `keys` - 键名列表
     ///
     /// # Returns
     ///
-    /// 返回成员列表
-    pub async fn smembers<K>(conn: &mut ConnectionManager, key: K) -> Result<Vec<String>>
+    /// 返回剩余秒数列表，-1表示永不过期，-2表示键不存在
+    pub async fn ttl_many<K>(conn: &mut ConnectionManager, keys: &[K]) -> Result<Vec<i64>>
     where
         K: ToRedisArgs + Send + Sync,
     {
-        let result: Vec<String> = conn.smembers(key).await?;
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut pipe = redis::pipe();
+        for key in keys {
+            pipe.ttl(key);
+        }
+        let result: Vec<i64> = pipe.query_async(conn).await?;
         Ok(result)
     }
 
-    /// 获取集合成员数量
+    /// 批量获取多个键的存在性、类型与剩余生存时间
+    ///
+    /// 每个键的 TYPE 与 TTL 在同一个 MULTI/EXEC 事务中一次发送，保证同一个键的各项信息一致；
+    /// 存在性由 TYPE 的结果（不存在时为 `none`）得出，无需额外的 EXISTS。
     ///
     /// # Arguments
     ///
-    /// * `key` - 集合键名
+    /// * `keys` - 键名列表
     ///
     /// # Returns
     ///
-    /// 返回成员数量
-    pub async fn scard<K>(conn: &mut ConnectionManager, key: K) -> Result<i32>
+    /// 返回与输入键顺序一一对应的键信息
+    pub async fn inspect_keys<K>(conn: &mut ConnectionManager, keys: &[K]) -> Result<Vec<KeyInfo>>
     where
         K: ToRedisArgs + Send + Sync,
     {
-        let result: i32 = conn.scard(key).await?;
-        Ok(result)
-    }
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
 
-    // ==================== 有序集合操作 ====================
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        for key in keys {
+            pipe.key_type(key).ttl(key);
+        }
+        let replies: Vec<(String, i64)> = pipe.query_async(conn).await?;
 
-    /// 向有序集合添加成员
+        Ok(replies
+            .into_iter()
+            .map(|(key_type, ttl)| {
+                let exists = key_type != "none";
+                KeyInfo {
+                    exists,
+                    key_type: exists.then_some(key_type),
+                    ttl,
+                }
+            })
+            .collect())
+    }
+
+    /// 获取键的绝对过期时间（Redis 7.0+）
     ///
     /// # Arguments
     ///
-    /// * `key` - 有序集合键名
-    /// * `score` - 分数
-    /// * `member` - 成员
+    /// * `key` - 键名
     ///
     /// # Returns
     ///
-    /// 返回添加的成员数量
-    pub async fn zadd<K, S, M>(
-        conn: &mut ConnectionManager,
-        key: K,
-        score: S,
-        member: M,
-    ) -> Result<i32>
+    /// 返回过期时刻的 Unix 时间戳（秒），-1表示永不过期，-2表示键不存在
+    pub async fn expire_time<K>(conn: &mut ConnectionManager, key: K) -> Result<i64>
     where
         K: ToRedisArgs + Send + Sync,
-        S: ToRedisArgs + Send + Sync,
-        M: ToRedisArgs + Send + Sync,
     {
-        let result: i32 = conn.zadd(key, member, score).await?;
+        let result: i64 = redis::cmd("EXPIRETIME").arg(key).query_async(conn).await?;
         Ok(result)
     }
 
-    /// 从有序集合移除成员
+    /// 获取键的绝对过期时间，毫秒精度（Redis 7.0+）
     ///
     /// # Arguments
     ///
-    /// * `key` - 有序集合键名
-    /// * `members` - 要移除的成员
+    /// * `key` - 键名
     ///
     /// # Returns
     ///
-    /// 返回移除的成员数量
-    pub async fn zrem<K, M>(conn: &mut ConnectionManager, key: K, members: M) -> Result<i32>
+    /// 返回过期时刻的 Unix 时间戳（毫秒），-1表示永不过期，-2表示键不存在
+    pub async fn pexpire_time<K>(conn: &mut ConnectionManager, key: K) -> Result<i64>
     where
         K: ToRedisArgs + Send + Sync,
-        M: ToRedisArgs + Send + Sync,
     {
-        let result: i32 = conn.zrem(key, members).await?;
+        let result: i64 = redis::cmd("PEXPIRETIME").arg(key).query_async(conn).await?;
         Ok(result)
     }
 
-    /// 获取有序集合指定范围的成员
+    /// 仅当键存在且永不过期时设置过期时间
+    ///
+    /// 通过 Lua 脚本原子地完成 TTL 检查与 EXPIRE，避免检查后键恰好过期的竞态。
     ///
     /// # Arguments
     ///
-    /// * `key` - 有序集合键名
-    /// * `start` - 开始索引
-    /// * `stop` - 结束索引
+    /// * `key` - 键名
+    /// * `seconds` - 过期时间（秒）
     ///
     /// # Returns
     ///
-    /// 返回成员列表
-    pub async fn zrange<K>(
+    /// 返回是否设置了过期时间
+    pub async fn ensure_expire<K>(
         conn: &mut ConnectionManager,
         key: K,
-        start: isize,
-        stop: isize,
-    ) -> Result<Vec<String>>
+        seconds: i64,
+    ) -> Result<bool>
     where
         K: ToRedisArgs + Send + Sync,
     {
-        let result: Vec<String> = conn.zrange(key, start, stop).await?;
+        let script = redis::Script::new(
+            r"
+            if redis.call('TTL', KEYS[1]) == -1 then
+                return redis.call('EXPIRE', KEYS[1], ARGV[1])
+            end
+            return 0
+            ",
+        );
+        let result: bool = script.key(key).arg(seconds).invoke_async(conn).await?;
         Ok(result)
     }
 
-    /// 获取有序集合成员数量
+    /// 获取两个字符串键的最长公共子序列（Redis 7.0+）
     ///
     /// # Arguments
     ///
-    /// * `key` - 有序集合键名
+    /// * `key1` - 第一个键名
+    /// * `key2` - 第二个键名
     ///
     /// # Returns
     ///
-    /// 返回成员数量
-    pub async fn zcard<K>(conn: &mut ConnectionManager, key: K) -> Result<i32>
+    /// 返回最长公共子序列
+    pub async fn lcs<K1, K2>(conn: &mut ConnectionManager, key1: K1, key2: K2) -> Result<String>
     where
-        K: ToRedisArgs + Send + Sync,
+        K1: ToRedisArgs + Send + Sync,
+        K2: ToRedisArgs + Send + Sync,
     {
-        let result: i32 = conn.zcard(key).await?;
+        let result: String = redis::cmd("LCS")
+            .arg(key1)
+            .arg(key2)
+            .query_async(conn)
+            .await?;
         Ok(result)
     }
 
-    // ==================== JSON 操作 (需要序列化/反序列化支持) ====================
-
-    /// 设置 JSON 对象
+    /// 获取两个字符串键的最长公共子序列长度（Redis 7.0+）
     ///
     /// # Arguments
     ///
-    /// * `key` - 键名
-    /// * `value` - 要序列化的对象
+    /// * `key1` - 第一个键名
+    /// * `key2` - 第二个键名
     ///
     /// # Returns
     ///
-    /// 返回操作结果
-    pub async fn set_json<K, V>(conn: &mut ConnectionManager, key: K, value: &V) -> Result<()>
+    /// 返回最长公共子序列的长度
+    pub async fn lcs_len<K1, K2>(conn: &mut ConnectionManager, key1: K1, key2: K2) -> Result<i64>
     where
-        K: ToRedisArgs + Send + Sync,
-        V: Serialize,
+        K1: ToRedisArgs + Send + Sync,
+        K2: ToRedisArgs + Send + Sync,
     {
-        let json_str = serde_json::to_string(value)
-            .map_err(|e| ConnectionError::Serialization(e.to_string()))?;
-        Self::set(conn, key, json_str).await
+        let result: i64 = redis::cmd("LCS")
+            .arg(key1)
+            .arg(key2)
+            .arg("LEN")
+            .query_async(conn)
+            .await?;
+        Ok(result)
     }
 
-    /// 获取 JSON 对象
+    /// 获取两个字符串键的最长公共子序列匹配位置（Redis 7.0+）
     ///
     /// # Arguments
     ///
-    /// * `key` - 键名
+    /// * `key1` - 第一个键名
+    /// * `key2` - 第二个键名
     ///
     /// # Returns
     ///
-    /// 返回反序列化的对象或 None
-    pub async fn get_json<K, V>(conn: &mut ConnectionManager, key: K) -> Result<Option<V>>
+    /// 返回每一段匹配在两个键中的位置及长度
+    pub async fn lcs_idx<K1, K2>(conn: &mut ConnectionManager, key1: K1, key2: K2) -> Result<LcsIdx>
     where
-        K: ToRedisArgs + Send + Sync,
-        V: for<'de> Deserialize<'de>,
+        K1: ToRedisArgs + Send + Sync,
+        K2: ToRedisArgs + Send + Sync,
     {
-        let json_str: Option<String> = Self::get(conn, key).await?;
-        match json_str {
-            Some(s) => {
-                let value = serde_json::from_str(&s)
-                    .map_err(|e| ConnectionError::Deserialization(e.to_string()))?;
-                Ok(Some(value))
-            }
-            None => Ok(None),
-        }
+        let result: LcsIdx = redis::cmd("LCS")
+            .arg(key1)
+            .arg(key2)
+            .arg("IDX")
+            .arg("WITHMATCHLEN")
+            .query_async(conn)
+            .await?;
+        Ok(result)
     }
 
-    // ==================== 通用结构体操作 ====================
-
-    /// 设置任意结构体对象
+    /// 获取字符串指定范围内的原始字节
+    ///
+    /// 与返回 String 的方法不同，非 UTF-8 数据会按原样保留。
     ///
     /// # Arguments
     ///
     /// * `key` - 键名
-    /// * `value` - 要存储的结构体对象
+    /// * `start` - 起始字节偏移量，负数表示从末尾倒数
+    /// * `end` - 结束字节偏移量（包含），负数表示从末尾倒数
     ///
     /// # Returns
     ///
-    /// 返回操作结果
+    /// 返回范围内的字节，键不存在或范围为空时返回空列表
+    pub async fn getrange_bytes<K>(
+        conn: &mut ConnectionManager,
+        key: K,
+        start: isize,
+        end: isize,
+    ) -> Result<Vec<u8>>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        let result: Vec<u8> = conn.getrange(key, start, end).await?;
+        Ok(result)
+    }
+
+    /// 从指定偏移量开始覆盖字符串的部分内容
     ///
-    /// # Examples
+    /// 偏移量超过当前字符串长度时，Redis 会用零字节（`\0`）填充中间的空缺；
+    /// 键不存在时视为空字符串。
     ///
-    /// ```
-    /// use serde::{Serialize, Deserialize};
+    /// # Arguments
     ///
-    /// #[derive(Serialize, Deserialize)]
-    /// struct User {
-    ///     id: u64,
-    ///     name: String,
-    ///     email: String,
-    /// }
+    /// * `key` - 键名
+    /// * `offset` - 起始字节偏移量
+    /// * `value` - 要写入的字节
     ///
-    /// let user = User {
-    ///     id: 1,
-    ///     name: "张三".to_string(),
-    ///     email: "zhangsan@example.com".to_string(),
-    /// };
+    /// # Returns
     ///
-    /// utils.set_struct("user:1", &user).await?;
-    /// ```
-    pub async fn set_struct<K, T>(conn: &mut ConnectionManager, key: K, value: &T) -> Result<()>
+    /// 返回修改后字符串的长度，写入后的长度超过服务器的 proto-max-bulk-len 时返回配置错误
+    pub async fn setrange<K>(
+        conn: &mut ConnectionManager,
+        key: K,
+        offset: usize,
+        value: &[u8],
+    ) -> Result<i32>
     where
         K: ToRedisArgs + Send + Sync,
-        T: Serialize,
     {
-        let json_str = serde_json::to_string(value)
-            .map_err(|e| ConnectionError::Serialization(e.to_string()))?;
-        Self::set(conn, key, json_str).await
+        let result: i32 = conn
+            .setrange(key, offset as isize, value)
+            .await
+            .map_err(Self::setrange_error)?;
+        Ok(result)
     }
 
-    /// 设置任意结构体对象并指定过期时间
+    /// 服务器拒绝超过 proto-max-bulk-len 的写入时返回配置错误，其他错误按原样转换
+    fn setrange_error(err: redis::RedisError) -> ConnectionError {
+        let too_large = err.kind() == redis::ErrorKind::ResponseError
+            && err
+                .detail()
+                .is_some_and(|detail| detail.contains("maximum allowed size"));
+        if too_large {
+            ConnectionError::Configuration(err.to_string())
+        } else {
+            ConnectionError::from(err)
+        }
+    }
+
+    /// 创建 BITFIELD 命令构建器，在一个字符串中读写多个定宽整数
     ///
     /// # Arguments
     ///
-    /// * `key` - 键名
-    /// * `value` - 要存储的结构体对象
-    /// * `seconds` - 过期时间（秒）
+    /// * `key` - 字符串键名
     ///
     /// # Returns
     ///
-    /// 返回操作结果
+    /// 返回构建器，例如
+    /// `RedisUtils::bitfield(conn, key).overflow(Overflow::Sat).incrby(BitFieldType::unsigned(8), 0, 1).execute()`
+    pub fn bitfield<K>(conn: &mut ConnectionManager, key: K) -> BitField<'_>
+    where
+        K: ToRedisArgs,
+    {
+        BitField::new(conn, key)
+    }
+
+    // ==================== 哈希表操作 ====================
+
+    /// 设置哈希表字段值
     ///
-    /// # Examples
+    /// # Arguments
     ///
-    /// ```
-    /// let user = User { /* ... */ };
-    /// utils.set_struct_ex("user:1", &user, 3600).await?; // 1小时后过期
-    /// ```
-    pub async fn set_struct_ex<K, T>(
+    /// * `key` - 哈希表键名
+    /// * `field` - 字段名
+    /// * `value` - 值
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn hset<K, F, V>(
         conn: &mut ConnectionManager,
         key: K,
-        value: &T,
-        seconds: usize,
-    ) -> Result<()>
+        field: F,
+        value: V,
+    ) -> Result<bool>
     where
         K: ToRedisArgs + Send + Sync,
-        T: Serialize,
+        F: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
     {
-        let json_str = serde_json::to_string(value)
-            .map_err(|e| ConnectionError::Serialization(e.to_string()))?;
-        Self::setex(conn, key, json_str, seconds).await
+        let result: bool = conn.hset(key, field, value).await?;
+        Ok(result)
     }
 
-    /// 获取任意结构体对象
+    /// 批量设置哈希表字段值
     ///
     /// # Arguments
     ///
-    /// * `key` - 键名
+    /// * `key` - 哈希表键名
+    /// * `pairs` - 字段名与值的列表，为空时不发送任何命令
     ///
     /// # Returns
     ///
-    /// 返回反序列化的结构体对象或 None
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// let user: Option<User> = utils.get_struct("user:1").await?;
-    /// match user {
-    ///     Some(u) => println!("用户名: {}", u.name),
-    ///     None => println!("用户不存在"),
-    /// }
-    /// ```
-    pub async fn get_struct<K, T>(conn: &mut ConnectionManager, key: K) -> Result<Option<T>>
+    /// 返回新创建的字段数量
+    pub async fn hset_multiple<K, F, V>(
+        conn: &mut ConnectionManager,
+        key: K,
+        pairs: &[(F, V)],
+    ) -> Result<i32>
     where
         K: ToRedisArgs + Send + Sync,
-        T: for<'de> Deserialize<'de>,
+        F: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
     {
-        let json_str: Option<String> = Self::get(conn, key).await?;
-        match json_str {
-            Some(s) => {
-                let value = serde_json::from_str(&s)
-                    .map_err(|e| ConnectionError::Deserialization(e.to_string()))?;
-                Ok(Some(value))
-            }
-            None => Ok(None),
+        if pairs.is_empty() {
+            return Ok(0);
         }
+
+        let result: i32 = redis::cmd("HSET")
+            .arg(key)
+            .arg(pairs)
+            .query_async(conn)
+            .await?;
+        Ok(result)
     }
 
-    /// 批量获取值
+    /// 获取哈希表字段值
     ///
     /// # Arguments
     ///
-    /// * `keys` - 键名列表
+    /// * `key` - 哈希表键名
+    /// * `field` - 字段名
     ///
     /// # Returns
     ///
-    /// 返回值列表
-    pub async fn mget<K>(conn: &mut ConnectionManager, keys: &[K]) -> Result<Vec<Option<String>>>
+    /// 返回字段值或 None
+    pub async fn hget<K, F, V>(conn: &mut ConnectionManager, key: K, field: F) -> Result<Option<V>>
     where
-        K: ToRedisArgs + Clone + Send + Sync,
+        K: ToRedisArgs + Send + Sync,
+        F: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
     {
-        let result: Vec<Option<String>> = conn.get(keys).await?;
+        let result: Option<V> = conn.hget(key, field).await?;
         Ok(result)
     }
 
-    /// 批量获取结构体对象
+    /// 获取哈希表所有字段和值
     ///
     /// # Arguments
     ///
-    /// * `keys` - 键名列表
+    /// * `key` - 哈希表键名
     ///
     /// # Returns
     ///
-    /// 返回结构体对象列表
-    ///
-    /// # Examples
-    ///
+    /// 返回字段值映射
+    pub async fn hgetall<K>(
+        conn: &mut ConnectionManager,
+        key: K,
+    ) -> Result<std::collections::HashMap<String, String>>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        let result: std::collections::HashMap<String, String> = conn.hgetall(key).await?;
+        Ok(result)
+    }
+
+    /// 删除哈希表字段
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 哈希表键名
+    /// * `fields` - 要删除的字段名列表
+    ///
+    /// # Returns
+    ///
+    /// 返回删除的字段数量
+    pub async fn hdel<K, F>(conn: &mut ConnectionManager, key: K, fields: F) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        F: ToRedisArgs + Send + Sync,
+    {
+        let result: i32 = conn.hdel(key, fields).await?;
+        Ok(result)
+    }
+
+    /// 检查哈希表字段是否存在
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 哈希表键名
+    /// * `field` - 字段名
+    ///
+    /// # Returns
+    ///
+    /// 返回是否存在
+    pub async fn hexists<K, F>(conn: &mut ConnectionManager, key: K, field: F) -> Result<bool>
+    where
+        K: ToRedisArgs + Send + Sync,
+        F: ToRedisArgs + Send + Sync,
+    {
+        let result: bool = conn.hexists(key, field).await?;
+        Ok(result)
+    }
+
+    /// 随机获取哈希表中的字段（Redis 6.2+）
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 哈希表键名
+    /// * `count` - 获取数量，正数返回不重复的字段，负数允许重复并返回 `|count|` 个字段
+    /// * `with_values` - 是否同时返回字段值
+    ///
+    /// # Returns
+    ///
+    /// 返回 (字段, 值) 列表，`with_values` 为 false 时值为 None
+    pub async fn hrandfield<K>(
+        conn: &mut ConnectionManager,
+        key: K,
+        count: i64,
+        with_values: bool,
+    ) -> Result<Vec<(String, Option<String>)>>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        let mut cmd = redis::cmd("HRANDFIELD");
+        cmd.arg(key).arg(count);
+        if !with_values {
+            let fields: Vec<String> = cmd.query_async(conn).await?;
+            return Ok(fields.into_iter().map(|field| (field, None)).collect());
+        }
+
+        cmd.arg("WITHVALUES");
+        let items: Vec<redis::Value> = cmd.query_async(conn).await?;
+
+        // RESP2 返回扁平的 [字段, 值, ...]，RESP3 返回 [[字段, 值], ...]
+        let pairs: Vec<(String, String)> = match items.first() {
+            Some(redis::Value::Array(_)) => items
+                .iter()
+                .map(FromRedisValue::from_redis_value)
+                .collect::<redis::RedisResult<_>>()?,
+            _ => {
+                let flat: Vec<String> = FromRedisValue::from_redis_values(&items)?;
+                if !flat.len().is_multiple_of(2) {
+                    return Err(ConnectionError::Deserialization(
+                        "HRANDFIELD WITHVALUES returned an odd number of items".to_string(),
+                    ));
+                }
+                flat.chunks_exact(2)
+                    .map(|pair| (pair[0].clone(), pair[1].clone()))
+                    .collect()
+            }
+        };
+
+        Ok(pairs
+            .into_iter()
+            .map(|(field, value)| (field, Some(value)))
+            .collect())
+    }
+
+    // ==================== 列表操作 ====================
+
+    /// 向列表左侧推入元素
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 列表键名
+    /// * `values` - 要推入的值
+    ///
+    /// # Returns
+    ///
+    /// 返回列表长度
+    pub async fn lpush<K, V>(conn: &mut ConnectionManager, key: K, values: V) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        let result: i32 = conn.lpush(key, values).await?;
+        Ok(result)
+    }
+
+    /// 向列表右侧推入元素
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 列表键名
+    /// * `values` - 要推入的值
+    ///
+    /// # Returns
+    ///
+    /// 返回列表长度
+    pub async fn rpush<K, V>(conn: &mut ConnectionManager, key: K, values: V) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        let result: i32 = conn.rpush(key, values).await?;
+        Ok(result)
+    }
+
+    /// 推入元素并将列表裁剪到指定的最大长度（定长列表）
+    ///
+    /// 推入与 LTRIM 在同一个 MULTI/EXEC 事务中执行，列表长度不会出现超过上限的窗口。
+    /// 裁剪时保留最新推入的一端：左侧推入保留头部，右侧推入保留尾部。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 列表键名
+    /// * `value` - 要推入的元素
+    /// * `max_len` - 列表的最大长度，必须大于 0
+    /// * `side` - 推入方向
+    ///
+    /// # Returns
+    ///
+    /// 返回裁剪后列表的长度
+    pub async fn push_capped<K, V>(
+        conn: &mut ConnectionManager,
+        key: K,
+        value: V,
+        max_len: usize,
+        side: End,
+    ) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        if max_len == 0 {
+            return Err(ConnectionError::Configuration(
+                "Capped list max_len must be greater than 0".to_string(),
+            ));
+        }
+
+        let max_len = max_len as isize;
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        match side {
+            End::Left => pipe.lpush(&key, value).ignore().ltrim(&key, 0, max_len - 1),
+            End::Right => pipe.rpush(&key, value).ignore().ltrim(&key, -max_len, -1),
+        }
+        .ignore()
+        .llen(&key);
+
+        let (len,): (i32,) = pipe.query_async(conn).await?;
+        Ok(len)
+    }
+
+    /// 从列表左侧弹出元素
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 列表键名
+    ///
+    /// # Returns
+    ///
+    /// 返回弹出的元素或 None
+    pub async fn lpop<K, V>(conn: &mut ConnectionManager, key: K) -> Result<Option<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        let result: Option<V> = conn.lpop(key, None).await?;
+        Ok(result)
+    }
+
+    /// 从列表右侧弹出元素
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 列表键名
+    ///
+    /// # Returns
+    ///
+    /// 返回弹出的元素或 None
+    pub async fn rpop<K, V>(conn: &mut ConnectionManager, key: K) -> Result<Option<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        let result: Option<V> = conn.rpop(key, None).await?;
+        Ok(result)
+    }
+
+    /// 从列表左侧一次弹出多个元素（Redis 6.2+）
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 列表键名
+    /// * `count` - 最多弹出的元素数量，超过列表长度时弹出全部元素
+    ///
+    /// # Returns
+    ///
+    /// 返回按弹出顺序排列的元素，列表不存在或 `count` 为 0 时返回空列表
+    pub async fn lpop_count<K, V>(
+        conn: &mut ConnectionManager,
+        key: K,
+        count: usize,
+    ) -> Result<Vec<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        let Some(count) = NonZeroUsize::new(count) else {
+            return Ok(Vec::new());
+        };
+        let result: Option<Vec<V>> = conn.lpop(key, Some(count)).await?;
+        Ok(result.unwrap_or_default())
+    }
+
+    /// 从列表右侧一次弹出多个元素（Redis 6.2+）
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 列表键名
+    /// * `count` - 最多弹出的元素数量，超过列表长度时弹出全部元素
+    ///
+    /// # Returns
+    ///
+    /// 返回按弹出顺序排列的元素（即从尾部开始），列表不存在或 `count` 为 0 时返回空列表
+    pub async fn rpop_count<K, V>(
+        conn: &mut ConnectionManager,
+        key: K,
+        count: usize,
+    ) -> Result<Vec<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        let Some(count) = NonZeroUsize::new(count) else {
+            return Ok(Vec::new());
+        };
+        let result: Option<Vec<V>> = conn.rpop(key, Some(count)).await?;
+        Ok(result.unwrap_or_default())
+    }
+
+    /// 从一个列表弹出元素并推入另一个列表
+    ///
+    /// 优先使用 LMOVE（Redis 6.2+），服务器不支持时退化为原子执行的 Lua 脚本。
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - 源列表键名
+    /// * `destination` - 目标列表键名
+    /// * `from` - 从源列表的哪一端弹出
+    /// * `to` - 推入目标列表的哪一端
+    ///
+    /// # Returns
+    ///
+    /// 返回被移动的元素，源列表为空时返回 None
+    pub async fn lmove<S, D, V>(
+        conn: &mut ConnectionManager,
+        source: S,
+        destination: D,
+        from: End,
+        to: End,
+    ) -> Result<Option<V>>
+    where
+        S: ToRedisArgs + Send + Sync,
+        D: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        let side = |end: End| match end {
+            End::Left => "LEFT",
+            End::Right => "RIGHT",
+        };
+
+        let native: redis::RedisResult<Option<V>> = redis::cmd("LMOVE")
+            .arg(&source)
+            .arg(&destination)
+            .arg(side(from))
+            .arg(side(to))
+            .query_async(conn)
+            .await;
+        match native {
+            Ok(value) => return Ok(value),
+            Err(e) => Self::check_native_error(e, "LMOVE")?,
+        }
+
+        let script = redis::Script::new(
+            r"
+                local value = redis.call(ARGV[1], KEYS[1])
+                if value then
+                    redis.call(ARGV[2], KEYS[2], value)
+                end
+                return value
+                ",
+        );
+        let pop = match from {
+            End::Left => "LPOP",
+            End::Right => "RPOP",
+        };
+        let push = match to {
+            End::Left => "LPUSH",
+            End::Right => "RPUSH",
+        };
+        let value: Option<V> = script
+            .key(&source)
+            .key(&destination)
+            .arg(pop)
+            .arg(push)
+            .invoke_async(conn)
+            .await?;
+        Ok(value)
+    }
+
+    /// 获取列表长度
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 列表键名
+    ///
+    /// # Returns
+    ///
+    /// 返回列表长度
+    pub async fn llen<K>(conn: &mut ConnectionManager, key: K) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        let result: i32 = conn.llen(key).await?;
+        Ok(result)
+    }
+
+    /// 获取列表指定范围的元素
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 列表键名
+    /// * `start` - 开始索引
+    /// * `stop` - 结束索引
+    ///
+    /// # Returns
+    ///
+    /// 返回元素列表，元素类型由调用方指定（如 `String`、`i64`）
+    pub async fn lrange<K, V>(
+        conn: &mut ConnectionManager,
+        key: K,
+        start: isize,
+        stop: isize,
+    ) -> Result<Vec<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        let result: Vec<V> = conn.lrange(key, start, stop).await?;
+        Ok(result)
+    }
+
+    // ==================== 集合操作 ====================
+
+    /// 向集合添加成员
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 集合键名
+    /// * `members` - 要添加的成员
+    ///
+    /// # Returns
+    ///
+    /// 返回添加的成员数量
+    pub async fn sadd<K, M>(conn: &mut ConnectionManager, key: K, members: M) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        let result: i32 = conn.sadd(key, members).await?;
+        Ok(result)
+    }
+
+    /// 从集合移除成员
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 集合键名
+    /// * `members` - 要移除的成员
+    ///
+    /// # Returns
+    ///
+    /// 返回移除的成员数量
+    pub async fn srem<K, M>(conn: &mut ConnectionManager, key: K, members: M) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        let result: i32 = conn.srem(key, members).await?;
+        Ok(result)
+    }
+
+    /// 检查成员是否在集合中
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 集合键名
+    /// * `member` - 成员
+    ///
+    /// # Returns
+    ///
+    /// 返回是否存在
+    pub async fn sismember<K, M>(conn: &mut ConnectionManager, key: K, member: M) -> Result<bool>
+    where
+        K: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        let result: bool = conn.sismember(key, member).await?;
+        Ok(result)
+    }
+
+    /// 批量检查成员是否在集合中
+    ///
+    /// 优先使用 SMISMEMBER（Redis 6.2+），服务器不支持时退化为一次流水线中的多个 SISMEMBER。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 集合键名
+    /// * `members` - 成员列表
+    ///
+    /// # Returns
+    ///
+    /// 返回与成员顺序一致的检查结果
+    pub async fn smismember<K, M>(
+        conn: &mut ConnectionManager,
+        key: K,
+        members: &[M],
+    ) -> Result<Vec<bool>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        if members.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let native: redis::RedisResult<Vec<bool>> = redis::cmd("SMISMEMBER")
+            .arg(&key)
+            .arg(members)
+            .query_async(conn)
+            .await;
+        match native {
+            Ok(result) => return Ok(result),
+            Err(e) => Self::check_native_error(e, "SMISMEMBER")?,
+        }
+
+        let mut pipe = redis::pipe();
+        for member in members {
+            pipe.sismember(&key, member);
+        }
+        let result: Vec<bool> = pipe.query_async(conn).await?;
+        Ok(result)
+    }
+
+    /// 获取集合所有成员
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 集合键名
+    ///
+    /// # Returns
+    ///
+    /// 返回成员列表，成员类型由调用方指定（如 `String`、`i64`）
+    pub async fn smembers<K, V>(conn: &mut ConnectionManager, key: K) -> Result<Vec<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        let result: Vec<V> = conn.smembers(key).await?;
+        Ok(result)
+    }
+
+    /// 获取集合所有成员并收集到调用方指定的集合类型中
+    ///
+    /// 成员直接从回复收集到目标集合，不会先构建中间的 `Vec<String>`。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 集合键名
+    ///
+    /// # Returns
+    ///
+    /// 返回收集后的成员，例如 `HashSet<String>`、`BTreeSet<String>` 或 `Vec<String>`
+    pub async fn smembers_collect<K, C>(conn: &mut ConnectionManager, key: K) -> Result<C>
+    where
+        K: ToRedisArgs + Send + Sync,
+        C: FromIterator<String>,
+    {
+        let value: redis::Value = conn.smembers(key).await?;
+        let members = match value {
+            redis::Value::Array(items) | redis::Value::Set(items) => items,
+            redis::Value::Nil => Vec::new(),
+            other => {
+                return Err(ConnectionError::Deserialization(format!(
+                    "Unexpected SMEMBERS reply: {other:?}"
+                )));
+            }
+        };
+
+        let result = members
+            .iter()
+            .map(String::from_redis_value)
+            .collect::<redis::RedisResult<C>>()?;
+        Ok(result)
+    }
+
+    /// 获取集合成员数量
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 集合键名
+    ///
+    /// # Returns
+    ///
+    /// 返回成员数量
+    pub async fn scard<K>(conn: &mut ConnectionManager, key: K) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        let result: i32 = conn.scard(key).await?;
+        Ok(result)
+    }
+
+    // ==================== 有序集合操作 ====================
+
+    /// 向有序集合添加成员
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 有序集合键名
+    /// * `score` - 分数
+    /// * `member` - 成员
+    ///
+    /// # Returns
+    ///
+    /// 返回添加的成员数量
+    pub async fn zadd<K, S, M>(
+        conn: &mut ConnectionManager,
+        key: K,
+        score: S,
+        member: M,
+    ) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        S: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        let result: i32 = conn.zadd(key, member, score).await?;
+        Ok(result)
+    }
+
+    /// 按完整选项批量向有序集合添加成员
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 有序集合键名
+    /// * `members` - (分数, 成员) 列表，不能为空
+    /// * `options` - ZADD 选项
+    ///
+    /// # Returns
+    ///
+    /// 返回新增的成员数量；启用 CH 时返回新增与分数被修改的成员总数
+    pub async fn zadd_opts<K, S, M>(
+        conn: &mut ConnectionManager,
+        key: K,
+        members: &[(S, M)],
+        options: ZAddOptions,
+    ) -> Result<i64>
+    where
+        K: ToRedisArgs + Send + Sync,
+        S: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        if members.is_empty() {
+            return Err(ConnectionError::Configuration(
+                "ZADD requires at least one member".to_string(),
+            ));
+        }
+
+        let mut cmd = Self::zadd_command(key, options)?;
+        if options.ch {
+            cmd.arg("CH");
+        }
+        for (score, member) in members {
+            cmd.arg(score).arg(member);
+        }
+
+        let result: i64 = cmd.query_async(conn).await?;
+        Ok(result)
+    }
+
+    /// 批量添加或更新成员，返回新增与分数被修改的成员总数（ZADD CH）
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 有序集合键名
+    /// * `members` - (分数, 成员) 列表，不能为空
+    ///
+    /// # Returns
+    ///
+    /// 返回新增与分数被修改的成员总数
+    pub async fn zadd_ch<K, S, M>(
+        conn: &mut ConnectionManager,
+        key: K,
+        members: &[(S, M)],
+    ) -> Result<i64>
+    where
+        K: ToRedisArgs + Send + Sync,
+        S: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        Self::zadd_opts(conn, key, members, ZAddOptions::default().ch()).await
+    }
+
+    /// 以 ZADD INCR 增加成员的分数，效果同 ZINCRBY 但可以附带写入条件
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 有序集合键名
+    /// * `increment` - 增量
+    /// * `member` - 成员
+    /// * `options` - ZADD 选项，CH 在 INCR 模式下没有效果
+    ///
+    /// # Returns
+    ///
+    /// 返回成员的新分数，因写入条件未满足而未修改时返回 None
+    pub async fn zadd_incr<K, M>(
+        conn: &mut ConnectionManager,
+        key: K,
+        increment: f64,
+        member: M,
+        options: ZAddOptions,
+    ) -> Result<Option<f64>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        let mut cmd = Self::zadd_command(key, options)?;
+        cmd.arg("INCR").arg(increment).arg(member);
+
+        let result: Option<f64> = cmd.query_async(conn).await?;
+        Ok(result)
+    }
+
+    /// 构建带有写入条件与比较条件的 ZADD 命令
+    fn zadd_command<K: ToRedisArgs>(key: K, options: ZAddOptions) -> Result<redis::Cmd> {
+        if options.condition == Some(ZAddCondition::NotExists) && options.comparison.is_some() {
+            return Err(ConnectionError::Configuration(
+                "ZADD NX cannot be combined with GT or LT".to_string(),
+            ));
+        }
+
+        let mut cmd = redis::cmd("ZADD");
+        cmd.arg(key);
+        match options.condition {
+            Some(ZAddCondition::NotExists) => {
+                cmd.arg("NX");
+            }
+            Some(ZAddCondition::Exists) => {
+                cmd.arg("XX");
+            }
+            None => {}
+        }
+        match options.comparison {
+            Some(ZAddComparison::GreaterThan) => {
+                cmd.arg("GT");
+            }
+            Some(ZAddComparison::LessThan) => {
+                cmd.arg("LT");
+            }
+            None => {}
+        }
+        Ok(cmd)
+    }
+
+    /// 从有序集合移除成员
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 有序集合键名
+    /// * `members` - 要移除的成员
+    ///
+    /// # Returns
+    ///
+    /// 返回移除的成员数量
+    pub async fn zrem<K, M>(conn: &mut ConnectionManager, key: K, members: M) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        let result: i32 = conn.zrem(key, members).await?;
+        Ok(result)
+    }
+
+    /// 获取有序集合指定范围的成员
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 有序集合键名
+    /// * `start` - 开始索引
+    /// * `stop` - 结束索引
+    ///
+    /// # Returns
+    ///
+    /// 返回成员列表，成员类型由调用方指定（如 `String`、`i64`）
+    pub async fn zrange<K, V>(
+        conn: &mut ConnectionManager,
+        key: K,
+        start: isize,
+        stop: isize,
+    ) -> Result<Vec<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        let result: Vec<V> = conn.zrange(key, start, stop).await?;
+        Ok(result)
+    }
+
+    /// 获取有序集合成员数量
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 有序集合键名
+    ///
+    /// # Returns
+    ///
+    /// 返回成员数量
+    pub async fn zcard<K>(conn: &mut ConnectionManager, key: K) -> Result<i32>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        let result: i32 = conn.zcard(key).await?;
+        Ok(result)
+    }
+
+    /// 批量获取有序集合成员的分数
+    ///
+    /// 优先使用 ZMSCORE（Redis 6.2+），服务器不支持时退化为一次流水线中的多个 ZSCORE。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 有序集合键名
+    /// * `members` - 成员列表
+    ///
+    /// # Returns
+    ///
+    /// 返回与成员顺序一致的分数列表，不存在的成员为 None
+    pub async fn zmscore<K, M>(
+        conn: &mut ConnectionManager,
+        key: K,
+        members: &[M],
+    ) -> Result<Vec<Option<f64>>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        if members.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let native: redis::RedisResult<Vec<Option<f64>>> = redis::cmd("ZMSCORE")
+            .arg(&key)
+            .arg(members)
+            .query_async(conn)
+            .await;
+        match native {
+            Ok(scores) => return Ok(scores),
+            Err(e) => Self::check_native_error(e, "ZMSCORE")?,
+        }
+
+        let mut pipe = redis::pipe();
+        for member in members {
+            pipe.zscore(&key, member);
+        }
+        let scores: Vec<Option<f64>> = pipe.query_async(conn).await?;
+        Ok(scores)
+    }
+
+    /// 原生命令失败后判断是否改用兼容实现
+    ///
+    /// 只有服务器回复未知命令（低于 Redis 6.2 的服务器不认识 GETDEL、GETEX、COPY、LMOVE、
+    /// SMISMEMBER、ZMSCORE）时返回 Ok，由调用方改用兼容实现；其他错误原样返回。
+    /// 判断只依据本次回复，不在进程内缓存，因此同时连接新旧服务器或服务器升级后都会选择正确的实现。
+    fn check_native_error(err: redis::RedisError, command: &str) -> Result<()> {
+        let unknown = err.kind() == redis::ErrorKind::ResponseError
+            && err
+                .detail()
+                .is_some_and(|detail| detail.starts_with("unknown command"));
+        if !unknown {
+            return Err(err.into());
+        }
+
+        log::debug!("Redis does not support {command}, using fallback");
+        Ok(())
+    }
+
+    /// 计算多个有序集合的并集并存入目标键（ZUNIONSTORE）
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - 目标键名
+    /// * `keys` - 源有序集合键名列表
+    /// * `weights` - 可选的各源集合分数权重，长度必须与 `keys` 一致
+    /// * `aggregate` - 同一成员分数的合并方式
+    ///
+    /// # Returns
+    ///
+    /// 返回目标有序集合的成员数量
+    pub async fn zunionstore<D, K>(
+        conn: &mut ConnectionManager,
+        destination: D,
+        keys: &[K],
+        weights: Option<&[f64]>,
+        aggregate: Aggregate,
+    ) -> Result<i64>
+    where
+        D: ToRedisArgs + Send + Sync,
+        K: ToRedisArgs + Send + Sync,
+    {
+        let mut cmd = redis::cmd("ZUNIONSTORE");
+        cmd.arg(destination);
+        Self::zset_combine_args(&mut cmd, keys, weights, aggregate)?;
+        let result: i64 = cmd.query_async(conn).await?;
+        Ok(result)
+    }
+
+    /// 计算多个有序集合的交集并存入目标键（ZINTERSTORE）
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - 目标键名
+    /// * `keys` - 源有序集合键名列表
+    /// * `weights` - 可选的各源集合分数权重，长度必须与 `keys` 一致
+    /// * `aggregate` - 同一成员分数的合并方式
+    ///
+    /// # Returns
+    ///
+    /// 返回目标有序集合的成员数量
+    pub async fn zinterstore<D, K>(
+        conn: &mut ConnectionManager,
+        destination: D,
+        keys: &[K],
+        weights: Option<&[f64]>,
+        aggregate: Aggregate,
+    ) -> Result<i64>
+    where
+        D: ToRedisArgs + Send + Sync,
+        K: ToRedisArgs + Send + Sync,
+    {
+        let mut cmd = redis::cmd("ZINTERSTORE");
+        cmd.arg(destination);
+        Self::zset_combine_args(&mut cmd, keys, weights, aggregate)?;
+        let result: i64 = cmd.query_async(conn).await?;
+        Ok(result)
+    }
+
+    /// 计算多个有序集合的并集（ZUNION，Redis 6.2+）
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - 源有序集合键名列表
+    /// * `weights` - 可选的各源集合分数权重，长度必须与 `keys` 一致
+    /// * `aggregate` - 同一成员分数的合并方式
+    ///
+    /// # Returns
+    ///
+    /// 返回按分数升序排列的 (成员, 分数) 列表
+    pub async fn zunion<K>(
+        conn: &mut ConnectionManager,
+        keys: &[K],
+        weights: Option<&[f64]>,
+        aggregate: Aggregate,
+    ) -> Result<Vec<(String, f64)>>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        let mut cmd = redis::cmd("ZUNION");
+        Self::zset_combine_args(&mut cmd, keys, weights, aggregate)?;
+        cmd.arg("WITHSCORES");
+        let result: Vec<(String, f64)> = cmd.query_async(conn).await?;
+        Ok(result)
+    }
+
+    /// 计算多个有序集合的交集（ZINTER，Redis 6.2+）
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - 源有序集合键名列表
+    /// * `weights` - 可选的各源集合分数权重，长度必须与 `keys` 一致
+    /// * `aggregate` - 同一成员分数的合并方式
+    ///
+    /// # Returns
+    ///
+    /// 返回按分数升序排列的 (成员, 分数) 列表
+    pub async fn zinter<K>(
+        conn: &mut ConnectionManager,
+        keys: &[K],
+        weights: Option<&[f64]>,
+        aggregate: Aggregate,
+    ) -> Result<Vec<(String, f64)>>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        let mut cmd = redis::cmd("ZINTER");
+        Self::zset_combine_args(&mut cmd, keys, weights, aggregate)?;
+        cmd.arg("WITHSCORES");
+        let result: Vec<(String, f64)> = cmd.query_async(conn).await?;
+        Ok(result)
+    }
+
+    /// 追加有序集合并集/交集命令共用的 numkeys、键、WEIGHTS 及 AGGREGATE 参数
+    fn zset_combine_args<K>(
+        cmd: &mut redis::Cmd,
+        keys: &[K],
+        weights: Option<&[f64]>,
+        aggregate: Aggregate,
+    ) -> Result<()>
+    where
+        K: ToRedisArgs,
+    {
+        if keys.is_empty() {
+            return Err(ConnectionError::Configuration(
+                "At least one source key is required".to_string(),
+            ));
+        }
+
+        cmd.arg(keys.len()).arg(keys);
+        if let Some(weights) = weights {
+            if weights.len() != keys.len() {
+                return Err(ConnectionError::Configuration(format!(
+                    "Expected {} weights, got {}",
+                    keys.len(),
+                    weights.len()
+                )));
+            }
+            cmd.arg("WEIGHTS").arg(weights);
+        }
+        cmd.arg("AGGREGATE").arg(aggregate.as_str());
+        Ok(())
+    }
+
+    // ==================== JSON 操作 (需要序列化/反序列化支持) ====================
+
+    /// 设置 JSON 对象
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 键名
+    /// * `value` - 要序列化的对象
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn set_json<K, V>(conn: &mut ConnectionManager, key: K, value: &V) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: Serialize,
+    {
+        let json_str = serde_json::to_string(value)
+            .map_err(|e| ConnectionError::Serialization(e.to_string()))?;
+        Self::set(conn, key, json_str).await
+    }
+
+    /// 获取 JSON 对象
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 键名
+    ///
+    /// # Returns
+    ///
+    /// 返回反序列化的对象或 None
+    pub async fn get_json<K, V>(conn: &mut ConnectionManager, key: K) -> Result<Option<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: for<'de> Deserialize<'de>,
+    {
+        let json_str: Option<String> = Self::get(conn, key).await?;
+        match json_str {
+            Some(s) => {
+                let value = serde_json::from_str(&s)
+                    .map_err(|e| ConnectionError::Deserialization(e.to_string()))?;
+                Ok(Some(value))
+            }
+            None => Ok(None),
+        }
+    }
+
+    // ==================== 通用结构体操作 ====================
+
+    /// 设置任意结构体对象
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 键名
+    /// * `value` - 要存储的结构体对象
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct User {
+    ///     id: u64,
+    ///     name: String,
+    ///     email: String,
+    /// }
+    ///
+    /// let user = User {
+    ///     id: 1,
+    ///     name: "张三".to_string(),
+    ///     email: "zhangsan@example.com".to_string(),
+    /// };
+    ///
+    /// utils.set_struct("user:1", &user).await?;
+    /// ```
+    pub async fn set_struct<K, T>(conn: &mut ConnectionManager, key: K, value: &T) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        T: Serialize,
+    {
+        let json_str = serde_json::to_string(value)
+            .map_err(|e| ConnectionError::Serialization(e.to_string()))?;
+        Self::set(conn, key, json_str).await
+    }
+
+    /// 仅在序列化结果与已存储的值不同时写入结构体对象
+    ///
+    /// 比较在服务器端通过 Lua 脚本完成，不需要额外的读取往返。注意 `HashMap`
+    /// 等无序容器的序列化顺序不固定，内容相同也可能被判断为已变化。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 键名
+    /// * `value` - 要存储的结构体对象
+    ///
+    /// # Returns
+    ///
+    /// 返回是否执行了写入，值未变化时返回 false
+    pub async fn set_struct_if_changed<K, T>(
+        conn: &mut ConnectionManager,
+        key: K,
+        value: &T,
+    ) -> Result<bool>
+    where
+        K: ToRedisArgs + Send + Sync,
+        T: Serialize,
+    {
+        let json_str = serde_json::to_string(value)
+            .map_err(|e| ConnectionError::Serialization(e.to_string()))?;
+
+        let script = redis::Script::new(
+            r"
+            if redis.call('GET', KEYS[1]) == ARGV[1] then
+                return 0
+            end
+            redis.call('SET', KEYS[1], ARGV[1])
+            return 1
+            ",
+        );
+        let written: bool = script.key(key).arg(json_str).invoke_async(conn).await?;
+        Ok(written)
+    }
+
+    /// 设置任意结构体对象并指定过期时间
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 键名
+    /// * `value` - 要存储的结构体对象
+    /// * `seconds` - 过期时间（秒）
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let user = User { /* ... */ };
+    /// utils.set_struct_ex("user:1", &user, 3600).await?; // 1小时后过期
+    /// ```
+    pub async fn set_struct_ex<K, T>(
+        conn: &mut ConnectionManager,
+        key: K,
+        value: &T,
+        seconds: usize,
+    ) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        T: Serialize,
+    {
+        let json_str = serde_json::to_string(value)
+            .map_err(|e| ConnectionError::Serialization(e.to_string()))?;
+        Self::setex(conn, key, json_str, seconds).await
+    }
+
+    /// 获取任意结构体对象
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 键名
+    ///
+    /// # Returns
+    ///
+    /// 返回反序列化的结构体对象或 None
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let user: Option<User> = utils.get_struct("user:1").await?;
+    /// match user {
+    ///     Some(u) => println!("用户名: {}", u.name),
+    ///     None => println!("用户不存在"),
+    /// }
     /// ```
+    pub async fn get_struct<K, T>(conn: &mut ConnectionManager, key: K) -> Result<Option<T>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        T: for<'de> Deserialize<'de>,
+    {
+        let json_str: Option<String> = Self::get(conn, key).await?;
+        match json_str {
+            Some(s) => {
+                let value = serde_json::from_str(&s)
+                    .map_err(|e| ConnectionError::Deserialization(e.to_string()))?;
+                Ok(Some(value))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// 批量获取值
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - 键名列表
+    ///
+    /// # Returns
+    ///
+    /// 返回值列表
+    pub async fn mget<K>(conn: &mut ConnectionManager, keys: &[K]) -> Result<Vec<Option<String>>>
+    where
+        K: ToRedisArgs + Clone + Send + Sync,
+    {
+        let result: Vec<Option<String>> = conn.get(keys).await?;
+        Ok(result)
+    }
+
+    /// 批量获取结构体对象
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - 键名列表
+    ///
+    /// # Returns
+    ///
+    /// 返回结构体对象列表
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
     /// let keys = vec!["user:1", "user:2", "user:3"];
     /// let users: Vec<Option<User>> = utils.mget_struct(&keys).await?;
     /// ```
-    pub async fn mget_struct<K, T>(
+    pub async fn mget_struct<K, T>(
+        conn: &mut ConnectionManager,
+        keys: &[K],
+    ) -> Result<Vec<Option<T>>>
+    where
+        K: ToRedisArgs + Clone + Send + Sync,
+        T: for<'de> Deserialize<'de>,
+    {
+        let json_strings: Vec<Option<String>> = Self::mget(conn, keys).await?;
+        let mut results = Vec::new();
+
+        for json_str in json_strings {
+            match json_str {
+                Some(s) => {
+                    let value = serde_json::from_str(&s)
+                        .map_err(|e| ConnectionError::Deserialization(e.to_string()))?;
+                    results.push(Some(value));
+                }
+                None => results.push(None),
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// 批量获取结构体对象，并按键名组织为映射
+    ///
+    /// 不存在的键以及无法反序列化的值不会出现在结果中。
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - 键名列表
+    ///
+    /// # Returns
+    ///
+    /// 返回键名到结构体对象的映射
+    pub async fn get_map_struct<T>(
+        conn: &mut ConnectionManager,
+        keys: &[String],
+    ) -> Result<HashMap<String, T>>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        if keys.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let json_strings: Vec<Option<String>> = Self::mget(conn, keys).await?;
+        let mut results = HashMap::with_capacity(keys.len());
+
+        for (key, json_str) in keys.iter().zip(json_strings) {
+            let Some(s) = json_str else {
+                continue;
+            };
+            match serde_json::from_str(&s) {
+                Ok(value) => {
+                    results.insert(key.clone(), value);
+                }
+                Err(e) => log::debug!("Skipping key {key}: failed to deserialize value: {e}"),
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// 获取列表指定范围的元素并逐个解析为结构体对象
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 列表键名
+    /// * `start` - 起始索引
+    /// * `stop` - 结束索引
+    ///
+    /// # Returns
+    ///
+    /// 返回结构体对象列表，某个元素解析失败时返回带有其在结果中序号的错误
+    pub async fn lrange_struct<K, T>(
+        conn: &mut ConnectionManager,
+        key: K,
+        start: isize,
+        stop: isize,
+    ) -> Result<Vec<T>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        T: DeserializeOwned,
+    {
+        let json_strings: Vec<String> = conn.lrange(key, start, stop).await?;
+
+        json_strings
+            .iter()
+            .enumerate()
+            .map(|(index, s)| {
+                serde_json::from_str(s).map_err(|e| {
+                    ConnectionError::Deserialization(format!(
+                        "Failed to decode list element #{index}: {e}"
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// 将结构体按字段存储为哈希表，每个结构体字段对应一个哈希字段
+    ///
+    /// 每个哈希字段的值是该属性的 JSON 编码（字符串会带引号），因此读取时可以还原原始类型，
+    /// 也可以单独更新某个属性。结构体必须能展开为单层对象，嵌套的对象或数组会返回序列化错误。
+    /// 哈希表中已有但结构体中不存在的字段会被保留。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 哈希表键名
+    /// * `value` - 要存储的结构体对象
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn hset_struct<K, T>(conn: &mut ConnectionManager, key: K, value: &T) -> Result<()>
+    where
+        K: ToRedisArgs + Send + Sync,
+        T: Serialize,
+    {
+        let json = serde_json::to_value(value)
+            .map_err(|e| ConnectionError::Serialization(e.to_string()))?;
+        let serde_json::Value::Object(fields) = json else {
+            return Err(ConnectionError::Serialization(
+                "hset_struct requires a value that serializes to an object".to_string(),
+            ));
+        };
+
+        let mut pairs = Vec::with_capacity(fields.len());
+        for (field, value) in fields {
+            if value.is_object() || value.is_array() {
+                return Err(ConnectionError::Serialization(format!(
+                    "Field `{field}` is nested and cannot be stored as a single hash field"
+                )));
+            }
+            pairs.push((field, value.to_string()));
+        }
+
+        if pairs.is_empty() {
+            return Ok(());
+        }
+
+        let _: () = conn.hset_multiple(key, &pairs).await?;
+        Ok(())
+    }
+
+    /// 读取由 [`RedisUtils::hset_struct`] 写入的哈希表并还原为结构体
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 哈希表键名
+    ///
+    /// # Returns
+    ///
+    /// 返回结构体对象，键不存在时返回 None
+    pub async fn hget_struct<K, T>(conn: &mut ConnectionManager, key: K) -> Result<Option<T>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        T: DeserializeOwned,
+    {
+        let fields: HashMap<String, String> = conn.hgetall(key).await?;
+        if fields.is_empty() {
+            return Ok(None);
+        }
+
+        Self::struct_from_fields(fields).map(Some)
+    }
+
+    /// 以一次流水线批量读取多个由 [`RedisUtils::hset_struct`] 写入的哈希表
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - 哈希表键名列表
+    ///
+    /// # Returns
+    ///
+    /// 返回键名到结构体的映射，不存在的键会被跳过；
+    /// 任一哈希表无法还原时返回带有该键名的反序列化错误
+    pub async fn hgetall_struct_multi<T>(
+        conn: &mut ConnectionManager,
+        keys: &[String],
+    ) -> Result<HashMap<String, T>>
+    where
+        T: DeserializeOwned,
+    {
+        if keys.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut pipe = redis::pipe();
+        for key in keys {
+            pipe.hgetall(key);
+        }
+        let replies: Vec<HashMap<String, String>> = pipe.query_async(conn).await?;
+
+        let mut result = HashMap::with_capacity(keys.len());
+        for (key, fields) in keys.iter().zip(replies) {
+            if fields.is_empty() {
+                continue;
+            }
+            let value = Self::struct_from_fields(fields).map_err(|e| match e {
+                ConnectionError::Deserialization(msg) => {
+                    ConnectionError::Deserialization(format!("Key `{key}`: {msg}"))
+                }
+                other => other,
+            })?;
+            result.insert(key.clone(), value);
+        }
+        Ok(result)
+    }
+
+    /// 将逐字段 JSON 编码的哈希表字段还原为结构体
+    fn struct_from_fields<T: DeserializeOwned>(fields: HashMap<String, String>) -> Result<T> {
+        let mut object = serde_json::Map::with_capacity(fields.len());
+        for (field, raw) in fields {
+            let value = serde_json::from_str(&raw).map_err(|e| {
+                ConnectionError::Deserialization(format!("Invalid JSON in field `{field}`: {e}"))
+            })?;
+            object.insert(field, value);
+        }
+
+        serde_json::from_value(serde_json::Value::Object(object))
+            .map_err(|e| ConnectionError::Deserialization(e.to_string()))
+    }
+
+    // ==================== 事务操作 ====================
+
+    /// 监视键的变化，用于手动实现乐观锁（WATCH）
+    ///
+    /// 典型流程为 WATCH → 读取并计算 → 以 `redis::pipe().atomic()` 发送 MULTI/EXEC，
+    /// 并将结果按 `Option<T>` 解析：被监视的键在此期间被修改时 EXEC 返回 nil，即 None，
+    /// 调用方应重新读取后重试。EXEC 或 [`RedisUtils::unwatch`] 之后监视自动解除。
+    ///
+    /// **注意**：WATCH 状态属于整条连接。`ConnectionManager` 是被所有克隆共享的多路复用连接，
+    /// 其他任务的命令会插入到 WATCH 与 EXEC 之间，可能导致误判冲突或错误地执行事务，
+    /// 重连后监视状态也会丢失。请为 WATCH 使用不与其他任务共享的专用连接，
+    /// 例如 `client.get_multiplexed_async_connection()` 新建的连接。
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - 专用连接
+    /// * `keys` - 要监视的键名列表
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn watch<C, K>(conn: &mut C, keys: &[K]) -> Result<()>
+    where
+        C: redis::aio::ConnectionLike + Send,
+        K: ToRedisArgs + Send + Sync,
+    {
+        if keys.is_empty() {
+            return Err(ConnectionError::Configuration(
+                "WATCH requires at least one key".to_string(),
+            ));
+        }
+
+        let _: () = redis::cmd("WATCH").arg(keys).query_async(conn).await?;
+        Ok(())
+    }
+
+    /// 解除当前连接上的所有监视（UNWATCH）
+    ///
+    /// 在 WATCH 之后决定不执行事务时调用。
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - 执行 WATCH 的专用连接
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn unwatch<C>(conn: &mut C) -> Result<()>
+    where
+        C: redis::aio::ConnectionLike + Send,
+    {
+        let _: () = redis::cmd("UNWATCH").query_async(conn).await?;
+        Ok(())
+    }
+
+    // ==================== 发布订阅操作 ====================
+
+    /// 获取当前活跃的频道列表
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - 可选的频道匹配模式，为 None 时返回所有活跃频道
+    ///
+    /// # Returns
+    ///
+    /// 返回至少有一个订阅者的频道名列表
+    pub async fn pubsub_channels(
+        conn: &mut ConnectionManager,
+        pattern: Option<&str>,
+    ) -> Result<Vec<String>> {
+        let mut cmd = redis::cmd("PUBSUB");
+        cmd.arg("CHANNELS");
+        if let Some(pattern) = pattern {
+            cmd.arg(pattern);
+        }
+        let result: Vec<String> = cmd.query_async(conn).await?;
+        Ok(result)
+    }
+
+    /// 获取指定频道的订阅者数量
+    ///
+    /// # Arguments
+    ///
+    /// * `channels` - 频道名列表
+    ///
+    /// # Returns
+    ///
+    /// 返回频道名到订阅者数量的映射
+    pub async fn pubsub_numsub(
+        conn: &mut ConnectionManager,
+        channels: &[&str],
+    ) -> Result<HashMap<String, i64>> {
+        if channels.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let reply: Vec<redis::Value> = redis::cmd("PUBSUB")
+            .arg("NUMSUB")
+            .arg(channels)
+            .query_async(conn)
+            .await?;
+
+        // NUMSUB 返回扁平数组：channel1, count1, channel2, count2, ...
+        if !reply.len().is_multiple_of(2) {
+            return Err(ConnectionError::Deserialization(format!(
+                "Unexpected PUBSUB NUMSUB reply length: {}",
+                reply.len()
+            )));
+        }
+
+        let mut result = HashMap::with_capacity(reply.len() / 2);
+        for pair in reply.chunks(2) {
+            let channel = String::from_redis_value(&pair[0])?;
+            let count = i64::from_redis_value(&pair[1])?;
+            result.insert(channel, count);
+        }
+
+        Ok(result)
+    }
+
+    /// 向频道发布消息
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - 频道名，可以是字符串或字节序列
+    /// * `message` - 消息内容
+    ///
+    /// # Returns
+    ///
+    /// 返回收到消息的订阅者数量
+    pub async fn publish<C, M>(conn: &mut ConnectionManager, channel: C, message: M) -> Result<i64>
+    where
+        C: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        let result: i64 = conn.publish(channel, message).await?;
+        Ok(result)
+    }
+
+    /// 在服务器的 notify-keyspace-events 配置中追加通知类别
+    ///
+    /// 读取当前配置后合并新的标志，不会关闭其他组件已开启的通知类别。
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` - 要开启的标志，例如 `Kx` 表示键空间通道上的过期事件
+    ///
+    /// # Returns
+    ///
+    /// 返回合并后的配置值
+    pub async fn enable_keyspace_events(
+        conn: &mut ConnectionManager,
+        flags: &str,
+    ) -> Result<String> {
+        let current: HashMap<String, String> = redis::cmd("CONFIG")
+            .arg("GET")
+            .arg("notify-keyspace-events")
+            .query_async(conn)
+            .await?;
+        let mut merged = current
+            .get("notify-keyspace-events")
+            .cloned()
+            .unwrap_or_default();
+
+        let missing: String = flags.chars().filter(|c| !merged.contains(*c)).collect();
+        if missing.is_empty() {
+            return Ok(merged);
+        }
+        merged.push_str(&missing);
+
+        let _: () = redis::cmd("CONFIG")
+            .arg("SET")
+            .arg("notify-keyspace-events")
+            .arg(&merged)
+            .query_async(conn)
+            .await?;
+        Ok(merged)
+    }
+
+    /// 向分片频道发布消息（SPUBLISH，Redis 7.0+）
+    ///
+    /// 在集群中消息只会在频道所属槽的分片内传播。连接的节点不负责该槽时服务器返回 MOVED 错误。
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - 分片频道名，可以是字符串或字节序列
+    /// * `message` - 消息内容
+    ///
+    /// # Returns
+    ///
+    /// 返回收到消息的订阅者数量
+    pub async fn spublish<C, M>(conn: &mut ConnectionManager, channel: C, message: M) -> Result<i64>
+    where
+        C: ToRedisArgs + Send + Sync,
+        M: ToRedisArgs + Send + Sync,
+    {
+        let result: i64 = conn.spublish(channel, message).await?;
+        Ok(result)
+    }
+
+    // ==================== 流操作 ====================
+
+    /// 获取消费者组的待确认消息摘要
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 流键名
+    /// * `group` - 消费者组名
+    ///
+    /// # Returns
+    ///
+    /// 返回待确认消息数量、起止 ID 以及各消费者的待确认数量
+    pub async fn xpending_summary<K, G>(
+        conn: &mut ConnectionManager,
+        key: K,
+        group: G,
+    ) -> Result<StreamPendingReply>
+    where
+        K: ToRedisArgs + Send + Sync,
+        G: ToRedisArgs + Send + Sync,
+    {
+        let result: StreamPendingReply = conn.xpending(key, group).await?;
+        Ok(result)
+    }
+
+    /// 将空闲时间超过阈值的待确认消息转移给指定消费者
+    ///
+    /// 只有空闲时间不小于 `min_idle` 的消息才会被认领，
+    /// 阈值应大于正常处理一条消息所需的时间，否则会抢走仍在处理中的消息。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 流键名
+    /// * `group` - 消费者组名
+    /// * `consumer` - 接收消息的消费者名
+    /// * `min_idle` - 最小空闲时间
+    /// * `start` - 起始 ID，首次调用通常为 "0-0"
+    /// * `count` - 本次最多认领的消息数量
+    ///
+    /// # Returns
+    ///
+    /// 返回认领到的消息以及下一次调用使用的游标 ID
+    pub async fn xautoclaim<K, G, C>(
+        conn: &mut ConnectionManager,
+        key: K,
+        group: G,
+        consumer: C,
+        min_idle: Duration,
+        start: &str,
+        count: usize,
+    ) -> Result<StreamAutoClaimReply>
+    where
+        K: ToRedisArgs + Send + Sync,
+        G: ToRedisArgs + Send + Sync,
+        C: ToRedisArgs + Send + Sync,
+    {
+        let options = StreamAutoClaimOptions::default().count(count);
+        let result: StreamAutoClaimReply = conn
+            .xautoclaim_options(
+                key,
+                group,
+                consumer,
+                min_idle.as_millis() as u64,
+                start,
+                options,
+            )
+            .await?;
+        Ok(result)
+    }
+
+    /// 获取流的概要信息
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 流键名
+    ///
+    /// # Returns
+    ///
+    /// 返回流的长度、最后生成的 ID 等信息
+    pub async fn xinfo_stream<K>(conn: &mut ConnectionManager, key: K) -> Result<StreamInfo>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        let result: StreamInfo = redis::cmd("XINFO")
+            .arg("STREAM")
+            .arg(key)
+            .query_async(conn)
+            .await?;
+        Ok(result)
+    }
+
+    /// 获取流上所有消费者组的信息
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 流键名
+    ///
+    /// # Returns
+    ///
+    /// 返回各消费者组的待确认数量、最后投递 ID 及积压量（lag）
+    pub async fn xinfo_groups<K>(conn: &mut ConnectionManager, key: K) -> Result<Vec<GroupInfo>>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        let result: Vec<GroupInfo> = redis::cmd("XINFO")
+            .arg("GROUPS")
+            .arg(key)
+            .query_async(conn)
+            .await?;
+        Ok(result)
+    }
+
+    // ==================== 键空间操作 ====================
+
+    /// 执行一次 SCAN 迭代
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - 游标，首次调用为 0
+    /// * `pattern` - 键匹配模式
+    /// * `count` - 每次迭代建议返回的键数量
+    ///
+    /// # Returns
+    ///
+    /// 返回下一次迭代的游标和本次匹配到的键，游标为 0 表示扫描结束
+    async fn scan_batch(
+        conn: &mut ConnectionManager,
+        cursor: u64,
+        pattern: &str,
+        count: usize,
+    ) -> Result<(u64, Vec<String>)> {
+        let result: (u64, Vec<String>) = redis::cmd("SCAN")
+            .arg(cursor)
+            .arg("MATCH")
+            .arg(pattern)
+            .arg("COUNT")
+            .arg(count)
+            .query_async(conn)
+            .await?;
+        Ok(result)
+    }
+
+    /// 按 SCAN 批次遍历匹配模式的键，并对每批键执行异步回调
+    ///
+    /// 回调完成后才会发起下一次 SCAN，因此可以在回调中休眠以控制清理速度。
+    /// 空批次不会触发回调。
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - 键匹配模式
+    /// * `count` - 每次迭代建议返回的键数量，为 0 时按 1 处理
+    /// * `f` - 对每批键执行的回调，返回错误时立即中止扫描并返回该错误
+    ///
+    /// # Returns
+    ///
+    /// 返回交给回调处理的键总数
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let mut deleter = conn.clone();
+    /// RedisUtils::scan_for_each(&mut conn, "session:*", 500, |keys| {
+    ///     let mut deleter = deleter.clone();
+    ///     let keys = keys.to_vec();
+    ///     Box::pin(async move {
+    ///         RedisUtils::del(&mut deleter, &keys).await?;
+    ///         tokio::time::sleep(Duration::from_millis(50)).await;
+    ///         Ok(())
+    ///     })
+    /// })
+    /// .await?;
+    /// ```
+    pub async fn scan_for_each<F>(
+        conn: &mut ConnectionManager,
+        pattern: &str,
+        count: usize,
+        mut f: F,
+    ) -> Result<usize>
+    where
+        F: for<'a> FnMut(&'a [String]) -> BoxFuture<'a, Result<()>>,
+    {
+        let mut cursor = 0;
+        let mut total = 0;
+
+        loop {
+            let (next, keys) = Self::scan_batch(conn, cursor, pattern, count.max(1)).await?;
+
+            if !keys.is_empty() {
+                f(&keys).await?;
+                total += keys.len();
+            }
+
+            if next == 0 {
+                break;
+            }
+            cursor = next;
+        }
+
+        Ok(total)
+    }
+
+    /// 检查是否存在至少一个匹配模式的键
+    ///
+    /// 基于 SCAN 逐批迭代，一旦某批返回了匹配的键就立即停止，不会遍历剩余的键空间。
+    /// 匹配稀疏时 SCAN 的大部分批次为空，因此使用较大的 COUNT 以减少往返次数。
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - 键匹配模式
+    ///
+    /// # Returns
+    ///
+    /// 存在匹配的键时返回 true
+    pub async fn any_key_matches(conn: &mut ConnectionManager, pattern: &str) -> Result<bool> {
+        let mut cursor = 0;
+        loop {
+            let (next, keys) = Self::scan_batch(conn, cursor, pattern, 1000).await?;
+            if !keys.is_empty() {
+                return Ok(true);
+            }
+            if next == 0 {
+                return Ok(false);
+            }
+            cursor = next;
+        }
+    }
+
+    /// 统计匹配模式的所有键的内存使用情况
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - 键匹配模式
+    /// * `sample_limit` - 最多统计的键数量，为 None 时统计所有匹配的键
+    ///
+    /// # Returns
+    ///
+    /// 返回总字节数、键数量以及占用最大的若干个键
+    pub async fn memory_usage_by_pattern(
+        conn: &mut ConnectionManager,
+        pattern: &str,
+        sample_limit: Option<usize>,
+    ) -> Result<MemoryReport> {
+        const TOP_N: usize = 10;
+
+        let mut report = MemoryReport::default();
+        let mut cursor = 0;
+
+        'scan: loop {
+            let (next, keys) = Self::scan_batch(conn, cursor, pattern, 100).await?;
+
+            for key in keys {
+                if sample_limit.is_some_and(|limit| report.key_count as usize >= limit) {
+                    report.sampled = true;
+                    break 'scan;
+                }
+
+                // 键可能在 SCAN 之后被删除，此时 MEMORY USAGE 返回 nil
+                let bytes: Option<u64> = redis::cmd("MEMORY")
+                    .arg("USAGE")
+                    .arg(&key)
+                    .query_async(conn)
+                    .await?;
+                let Some(bytes) = bytes else {
+                    continue;
+                };
+
+                report.total_bytes += bytes;
+                report.key_count += 1;
+                report.largest.push((key, bytes));
+                if report.largest.len() > TOP_N {
+                    report.largest.sort_by_key(|(_, bytes)| Reverse(*bytes));
+                    report.largest.truncate(TOP_N);
+                }
+            }
+
+            cursor = next;
+            if cursor == 0 {
+                break;
+            }
+        }
+
+        report.largest.sort_by_key(|(_, bytes)| Reverse(*bytes));
+        Ok(report)
+    }
+
+    /// 查找占用内存超过阈值的大键，相当于可编程调用的 `redis-cli --bigkeys`
+    ///
+    /// 每个键通过一次流水线获取类型和 MEMORY USAGE，只对超过阈值的键再查询元素数量。
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - 键匹配模式，为 None 时检查所有键
+    /// * `threshold_bytes` - 字节数阈值，超过该值的键会被报告
+    /// * `sample` - 最多检查的键数量，为 None 时检查所有匹配的键
+    ///
+    /// # Returns
+    ///
+    /// 返回超过阈值的键，按字节数降序排列
+    pub async fn find_big_keys(
+        conn: &mut ConnectionManager,
+        pattern: Option<&str>,
+        threshold_bytes: u64,
+        sample: Option<usize>,
+    ) -> Result<Vec<BigKey>> {
+        let pattern = pattern.unwrap_or("*");
+        let mut big_keys = Vec::new();
+        let mut checked = 0;
+        let mut cursor = 0;
+
+        'scan: loop {
+            let (next, keys) = Self::scan_batch(conn, cursor, pattern, 100).await?;
+
+            for key in keys {
+                if sample.is_some_and(|limit| checked >= limit) {
+                    break 'scan;
+                }
+                checked += 1;
+
+                let (key_type, bytes): (String, Option<u64>) = redis::pipe()
+                    .cmd("TYPE")
+                    .arg(&key)
+                    .cmd("MEMORY")
+                    .arg("USAGE")
+                    .arg(&key)
+                    .query_async(conn)
+                    .await?;
+                // 键可能在 SCAN 之后被删除
+                let Some(bytes) = bytes.filter(|bytes| *bytes > threshold_bytes) else {
+                    continue;
+                };
+
+                let length_command = match key_type.as_str() {
+                    "string" => Some("STRLEN"),
+                    "list" => Some("LLEN"),
+                    "set" => Some("SCARD"),
+                    "zset" => Some("ZCARD"),
+                    "hash" => Some("HLEN"),
+                    "stream" => Some("XLEN"),
+                    _ => None,
+                };
+                let elements = match length_command {
+                    Some(command) => Some(redis::cmd(command).arg(&key).query_async(conn).await?),
+                    None => None,
+                };
+
+                big_keys.push(BigKey {
+                    key,
+                    key_type,
+                    bytes,
+                    elements,
+                });
+            }
+
+            cursor = next;
+            if cursor == 0 {
+                break;
+            }
+        }
+
+        big_keys.sort_by_key(|big_key| Reverse(big_key.bytes));
+        Ok(big_keys)
+    }
+
+    /// 估算匹配模式的键数量
+    ///
+    /// 只执行有限次数的 SCAN 迭代，按已扫描部分的匹配密度结合 DBSIZE 推算总数。
+    /// SCAN 的 COUNT 只是每次迭代检查元素数量的近似值，因此结果只适合作为量级参考；
+    /// 若在迭代次数用尽前扫描已经结束，则返回精确的匹配数量。
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - 键匹配模式
+    /// * `sample_iterations` - 最多执行的 SCAN 迭代次数
+    ///
+    /// # Returns
+    ///
+    /// 返回估算的匹配键数量
+    pub async fn estimate_keys(
+        conn: &mut ConnectionManager,
+        pattern: &str,
+        sample_iterations: usize,
+    ) -> Result<u64> {
+        const SCAN_COUNT: usize = 1000;
+
+        let mut cursor = 0;
+        let mut matched: u64 = 0;
+        let mut iterations = 0;
+
+        while iterations < sample_iterations {
+            let (next, keys) = Self::scan_batch(conn, cursor, pattern, SCAN_COUNT).await?;
+            matched += keys.len() as u64;
+            iterations += 1;
+
+            cursor = next;
+            if cursor == 0 {
+                return Ok(matched);
+            }
+        }
+
+        let total: u64 = redis::cmd("DBSIZE").query_async(conn).await?;
+        let examined = (iterations * SCAN_COUNT) as u64;
+        if examined == 0 || examined >= total {
+            return Ok(matched);
+        }
+
+        let estimate = matched as f64 / examined as f64 * total as f64;
+        Ok(estimate.round() as u64)
+    }
+
+    /// 为匹配模式的所有键设置过期时间
+    ///
+    /// 使用 SCAN 遍历键空间（不会像 KEYS 那样阻塞服务器），每批匹配到的键通过一次流水线发送 EXPIRE。
+    /// `overwrite` 为 false 时使用 `EXPIRE ... NX`（Redis 7.0+），只为当前没有过期时间的键设置。
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - 键匹配模式
+    /// * `seconds` - 过期时间（秒）
+    /// * `batch_size` - 每次 SCAN 迭代建议返回的键数量
+    /// * `overwrite` - 是否覆盖已有的过期时间
+    ///
+    /// # Returns
+    ///
+    /// 返回实际设置了过期时间的键数量
+    pub async fn expire_by_pattern(
+        conn: &mut ConnectionManager,
+        pattern: &str,
+        seconds: i64,
+        batch_size: usize,
+        overwrite: bool,
+    ) -> Result<u64> {
+        let mut cursor = 0;
+        let mut updated = 0;
+
+        loop {
+            let (next, keys) = Self::scan_batch(conn, cursor, pattern, batch_size.max(1)).await?;
+
+            if !keys.is_empty() {
+                let mut pipe = redis::pipe();
+                for key in &keys {
+                    let cmd = pipe.cmd("EXPIRE").arg(key).arg(seconds);
+                    if !overwrite {
+                        cmd.arg("NX");
+                    }
+                }
+                let results: Vec<bool> = pipe.query_async(conn).await?;
+                updated += results.into_iter().filter(|set| *set).count() as u64;
+            }
+
+            cursor = next;
+            if cursor == 0 {
+                break;
+            }
+        }
+
+        Ok(updated)
+    }
+
+    // ==================== 服务器操作 ====================
+
+    /// 等待之前的写命令被写入本地及副本的 AOF 文件（Redis 7.2+）
+    ///
+    /// # Arguments
+    ///
+    /// * `numlocal` - 需要确认的本地 AOF 数量，通常为 0 或 1
+    /// * `numreplicas` - 需要确认的副本数量
+    /// * `timeout` - 最长等待时间，为 0 时一直等待
+    ///
+    /// # Returns
+    ///
+    /// 返回已完成 AOF 写入的本地数量与副本数量
+    pub async fn waitaof(
+        conn: &mut ConnectionManager,
+        numlocal: usize,
+        numreplicas: usize,
+        timeout: Duration,
+    ) -> Result<(i64, i64)> {
+        let reply: Vec<i64> = redis::cmd("WAITAOF")
+            .arg(numlocal)
+            .arg(numreplicas)
+            .arg(timeout.as_millis() as u64)
+            .query_async(conn)
+            .await?;
+
+        match reply.as_slice() {
+            [local, replicas] => Ok((*local, *replicas)),
+            _ => Err(ConnectionError::Deserialization(format!(
+                "Unexpected WAITAOF reply: {reply:?}"
+            ))),
+        }
+    }
+
+    /// 列出所有客户端连接
+    ///
+    /// # Returns
+    ///
+    /// 返回客户端连接信息列表
+    pub async fn client_list(conn: &mut ConnectionManager) -> Result<Vec<ClientInfo>> {
+        let output: String = redis::cmd("CLIENT").arg("LIST").query_async(conn).await?;
+
+        output
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| ClientInfo::parse_line(line).map_err(ConnectionError::Deserialization))
+            .collect()
+    }
+
+    /// 根据客户端 ID 断开连接
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - 客户端 ID
+    ///
+    /// # Returns
+    ///
+    /// 返回是否断开了连接
+    pub async fn client_kill_id(conn: &mut ConnectionManager, id: u64) -> Result<bool> {
+        let killed: i64 = redis::cmd("CLIENT")
+            .arg("KILL")
+            .arg("ID")
+            .arg(id)
+            .query_async(conn)
+            .await?;
+        Ok(killed > 0)
+    }
+
+    /// 暂停客户端命令的处理，到期后自动恢复（CLIENT PAUSE）
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - 暂停时长，按毫秒向下取整
+    /// * `mode` - 暂停范围，[`PauseMode::Write`] 需要 Redis 6.2+
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果，服务器不支持 WRITE 模式时返回配置错误
+    pub async fn client_pause(
+        conn: &mut ConnectionManager,
+        duration: Duration,
+        mode: PauseMode,
+    ) -> Result<()> {
+        let mut cmd = redis::cmd("CLIENT");
+        cmd.arg("PAUSE").arg(duration.as_millis() as u64);
+        if mode == PauseMode::Write {
+            cmd.arg("WRITE");
+        }
+
+        let result: redis::RedisResult<()> = cmd.query_async(conn).await;
+        match result {
+            Ok(()) => Ok(()),
+            // 6.2 之前的版本不认识 WRITE 参数，会返回语法错误
+            Err(e) if mode == PauseMode::Write && e.kind() == redis::ErrorKind::ResponseError => {
+                Err(ConnectionError::Configuration(format!(
+                    "CLIENT PAUSE WRITE is not supported by this server (requires Redis 6.2+): {e}"
+                )))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// 提前恢复被 CLIENT PAUSE 暂停的客户端（CLIENT UNPAUSE，Redis 6.2+）
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn client_unpause(conn: &mut ConnectionManager) -> Result<()> {
+        let _: () = redis::cmd("CLIENT")
+            .arg("UNPAUSE")
+            .query_async(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// 获取服务器当前时间
+    ///
+    /// # Returns
+    ///
+    /// 返回服务器的 Unix 时间戳秒数及当前秒内的微秒数
+    pub async fn server_time(conn: &mut ConnectionManager) -> Result<(u64, u64)> {
+        let (seconds, micros): (u64, u64) = redis::cmd("TIME").query_async(conn).await?;
+        Ok((seconds, micros))
+    }
+
+    /// 获取服务器当前时间的毫秒时间戳
+    ///
+    /// # Returns
+    ///
+    /// 返回服务器的 Unix 毫秒时间戳
+    pub async fn server_time_millis(conn: &mut ConnectionManager) -> Result<u64> {
+        let (seconds, micros) = Self::server_time(conn).await?;
+        Ok(seconds * 1000 + micros / 1000)
+    }
+
+    /// 获取服务器版本（INFO server 中的 `redis_version` 字段）
+    ///
+    /// # Returns
+    ///
+    /// 返回可比较的版本号
+    pub async fn server_version(conn: &mut ConnectionManager) -> Result<Version> {
+        let info: String = redis::cmd("INFO").arg("server").query_async(conn).await?;
+        let version = info
+            .lines()
+            .find_map(|line| line.strip_prefix("redis_version:"))
+            .ok_or_else(|| {
+                ConnectionError::Deserialization(
+                    "INFO reply has no redis_version field".to_string(),
+                )
+            })?;
+        Version::parse(version).map_err(ConnectionError::Deserialization)
+    }
+
+    /// 获取所有延迟事件的最新采样（LATENCY LATEST）
+    ///
+    /// 需要先通过 `CONFIG SET latency-monitor-threshold` 开启延迟监控。
+    ///
+    /// # Returns
+    ///
+    /// 返回各事件的最近一次和最大延迟
+    pub async fn latency_latest(conn: &mut ConnectionManager) -> Result<Vec<LatencyEvent>> {
+        let result: Vec<LatencyEvent> = redis::cmd("LATENCY")
+            .arg("LATEST")
+            .query_async(conn)
+            .await?;
+        Ok(result)
+    }
+
+    /// 获取指定事件的延迟历史（LATENCY HISTORY）
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - 事件名称，例如 `command`
+    ///
+    /// # Returns
+    ///
+    /// 返回 (Unix 时间戳（秒）, 延迟（毫秒）) 列表，按时间先后排列
+    pub async fn latency_history(
+        conn: &mut ConnectionManager,
+        event: &str,
+    ) -> Result<Vec<(i64, i64)>> {
+        let result: Vec<(i64, i64)> = redis::cmd("LATENCY")
+            .arg("HISTORY")
+            .arg(event)
+            .query_async(conn)
+            .await?;
+        Ok(result)
+    }
+
+    /// 检查服务器是否支持指定命令（COMMAND INFO）
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - 命令名，不区分大小写，例如 `GETDEL`
+    ///
+    /// # Returns
+    ///
+    /// 服务器支持该命令时返回 true
+    pub async fn command_exists(conn: &mut ConnectionManager, name: &str) -> Result<bool> {
+        let support = Self::command_support(conn, &[name]).await?;
+        Ok(support.get(name).copied().unwrap_or(false))
+    }
+
+    /// 一次性探测多个命令的支持情况，适合在启动时计算并缓存能力表
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - 命令名列表
+    ///
+    /// # Returns
+    ///
+    /// 返回命令名（与传入时一致）到是否支持的映射
+    pub async fn command_support(
+        conn: &mut ConnectionManager,
+        names: &[&str],
+    ) -> Result<HashMap<String, bool>> {
+        if names.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        // 未知命令在回复中对应 nil
+        let infos: Vec<redis::Value> = redis::cmd("COMMAND")
+            .arg("INFO")
+            .arg(names)
+            .query_async(conn)
+            .await?;
+
+        Ok(names
+            .iter()
+            .zip(infos)
+            .map(|(name, info)| (name.to_string(), info != redis::Value::Nil))
+            .collect())
+    }
+
+    /// 发起协调式的主从故障转移（Redis 6.2+）
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - 可选的目标副本地址 (host, port)，为 None 时由服务器选择
+    /// * `force` - 超时后是否强制切换，需要同时指定 `to` 和 `timeout`
+    /// * `timeout` - 可选的超时时间
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果，已有故障转移在进行中或被中止时返回 `ConnectionError::Failover`
+    pub async fn failover(
+        conn: &mut ConnectionManager,
+        to: Option<(String, u16)>,
+        force: bool,
+        timeout: Option<Duration>,
+    ) -> Result<()> {
+        if force && (to.is_none() || timeout.is_none()) {
+            return Err(ConnectionError::Configuration(
+                "FAILOVER FORCE requires both a target and a timeout".to_string(),
+            ));
+        }
+
+        let mut cmd = redis::cmd("FAILOVER");
+        if let Some((host, port)) = to {
+            cmd.arg("TO").arg(host).arg(port);
+            if force {
+                cmd.arg("FORCE");
+            }
+        }
+        if let Some(timeout) = timeout {
+            cmd.arg("TIMEOUT").arg(timeout.as_millis() as u64);
+        }
+
+        let _: () = cmd.query_async(conn).await.map_err(Self::failover_error)?;
+        Ok(())
+    }
+
+    /// 中止正在进行的故障转移
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果，没有正在进行的故障转移时返回 `ConnectionError::Failover`
+    pub async fn failover_abort(conn: &mut ConnectionManager) -> Result<()> {
+        let _: () = redis::cmd("FAILOVER")
+            .arg("ABORT")
+            .query_async(conn)
+            .await
+            .map_err(Self::failover_error)?;
+        Ok(())
+    }
+
+    /// 将服务器返回的故障转移错误转换为 `ConnectionError::Failover`
+    fn failover_error(err: redis::RedisError) -> ConnectionError {
+        if err.kind() == redis::ErrorKind::ResponseError {
+            ConnectionError::Failover(err.to_string())
+        } else {
+            ConnectionError::from(err)
+        }
+    }
+
+    // ==================== 持久化操作 ====================
+
+    /// 在后台生成 RDB 快照
+    ///
+    /// 命令返回时快照尚未完成，可通过 [`RedisUtils::lastsave`] 的时间戳变化确认完成。
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果，已有 BGSAVE 或 AOF 重写在进行中时返回 `ConnectionError::SaveInProgress`
+    pub async fn bgsave(conn: &mut ConnectionManager) -> Result<()> {
+        let _: () = redis::cmd("BGSAVE")
+            .query_async(conn)
+            .await
+            .map_err(Self::save_error)?;
+        Ok(())
+    }
+
+    /// 同步生成 RDB 快照
+    ///
+    /// **警告**：SAVE 会阻塞服务器直到快照写完，期间不处理任何其他客户端的命令，
+    /// 生产环境应使用 [`RedisUtils::bgsave`]。
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果，已有后台保存在进行中时返回 `ConnectionError::SaveInProgress`
+    pub async fn save(conn: &mut ConnectionManager) -> Result<()> {
+        let _: () = redis::cmd("SAVE")
+            .query_async(conn)
+            .await
+            .map_err(Self::save_error)?;
+        Ok(())
+    }
+
+    /// 获取最近一次成功生成快照的时间
+    ///
+    /// # Returns
+    ///
+    /// 返回 Unix 时间戳（秒）
+    pub async fn lastsave(conn: &mut ConnectionManager) -> Result<i64> {
+        let timestamp: i64 = redis::cmd("LASTSAVE").query_async(conn).await?;
+        Ok(timestamp)
+    }
+
+    /// 将"持久化正在进行中"的错误转换为 `ConnectionError::SaveInProgress`
+    fn save_error(err: redis::RedisError) -> ConnectionError {
+        let in_progress = err.kind() == redis::ErrorKind::ResponseError
+            && err
+                .detail()
+                .is_some_and(|detail| detail.contains("in progress"));
+        if in_progress {
+            ConnectionError::SaveInProgress(err.to_string())
+        } else {
+            ConnectionError::from(err)
+        }
+    }
+
+    // ==================== ACL 操作 ====================
+
+    /// 获取当前连接认证的 ACL 用户名（Redis 6.0+）
+    ///
+    /// # Returns
+    ///
+    /// 返回用户名，未配置 ACL 时为 `default`
+    pub async fn acl_whoami(conn: &mut ConnectionManager) -> Result<String> {
+        let result: String = redis::cmd("ACL").arg("WHOAMI").query_async(conn).await?;
+        Ok(result)
+    }
+
+    /// 获取 ACL 用户的权限信息（Redis 6.0+）
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - 用户名
+    ///
+    /// # Returns
+    ///
+    /// 返回用户的标志、键模式及命令规则，用户不存在时返回配置错误
+    pub async fn acl_getuser(conn: &mut ConnectionManager, username: &str) -> Result<AclUser> {
+        let result: Option<AclUser> = redis::cmd("ACL")
+            .arg("GETUSER")
+            .arg(username)
+            .query_async(conn)
+            .await?;
+        result.ok_or_else(|| {
+            ConnectionError::Configuration(format!("ACL user `{username}` does not exist"))
+        })
+    }
+
+    // ==================== 脚本操作 ====================
+
+    /// 将 Lua 脚本加载到服务器的脚本缓存中
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Lua 脚本源码
+    ///
+    /// # Returns
+    ///
+    /// 返回脚本的 SHA1 摘要，可用于 EVALSHA 调用
+    pub async fn script_load(conn: &mut ConnectionManager, source: &str) -> Result<String> {
+        let sha: String = redis::cmd("SCRIPT")
+            .arg("LOAD")
+            .arg(source)
+            .query_async(conn)
+            .await
+            .map_err(Self::script_error)?;
+        Ok(sha)
+    }
+
+    /// 检查脚本是否已存在于服务器的脚本缓存中
+    ///
+    /// # Arguments
+    ///
+    /// * `shas` - 脚本 SHA1 摘要列表
+    ///
+    /// # Returns
+    ///
+    /// 返回与输入顺序一致的存在性列表
+    pub async fn script_exists(conn: &mut ConnectionManager, shas: &[&str]) -> Result<Vec<bool>> {
+        if shas.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let result: Vec<bool> = redis::cmd("SCRIPT")
+            .arg("EXISTS")
+            .arg(shas)
+            .query_async(conn)
+            .await
+            .map_err(Self::script_error)?;
+        Ok(result)
+    }
+
+    /// 将脚本管理命令的服务端错误回复转换为脚本错误，连接错误保持原有类型
+    fn script_error(err: redis::RedisError) -> ConnectionError {
+        if err.kind() == redis::ErrorKind::ResponseError {
+            ConnectionError::Script(err.to_string())
+        } else {
+            ConnectionError::from(err)
+        }
+    }
+
+    // ==================== 集群操作 ====================
+
+    /// 获取集群状态信息
+    ///
+    /// # Returns
+    ///
+    /// 返回 CLUSTER INFO 中的字段映射，如 cluster_state、cluster_known_nodes
+    pub async fn cluster_info(conn: &mut ConnectionManager) -> Result<HashMap<String, String>> {
+        let output: String = redis::cmd("CLUSTER").arg("INFO").query_async(conn).await?;
+
+        Ok(output
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+            .collect())
+    }
+
+    /// 获取集群节点拓扑
+    ///
+    /// # Returns
+    ///
+    /// 返回集群中所有节点的信息
+    pub async fn cluster_nodes(conn: &mut ConnectionManager) -> Result<Vec<ClusterNode>> {
+        let output: String = redis::cmd("CLUSTER").arg("NODES").query_async(conn).await?;
+
+        output
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| ClusterNode::parse_line(line).map_err(ConnectionError::Deserialization))
+            .collect()
+    }
+
+    // ==================== 原始命令操作 ====================
+
+    /// 发送一条预先编码的命令并读取一条回复
+    ///
+    /// 支持两种格式：以 `*` 开头的 RESP 数组帧（如 `*1\r\n$4\r\nPING\r\n`），
+    /// 以及以空格分隔的内联命令（如 `PING\r\n`）。命令会被解析后经由连接管理器发送，
+    /// 因此同样享有自动重连，错误也统一映射为 [`ConnectionError`]。
+    /// 每次只能发送一条命令。
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - 编码后的命令
+    ///
+    /// # Returns
+    ///
+    /// 返回原始回复
+    pub async fn send_raw(conn: &mut ConnectionManager, bytes: &[u8]) -> Result<redis::Value> {
+        let args = Self::decode_raw_command(bytes)?;
+        let Some((name, rest)) = args.split_first() else {
+            return Err(ConnectionError::Configuration(
+                "Raw command is empty".to_string(),
+            ));
+        };
+
+        let mut cmd = redis::cmd(&String::from_utf8_lossy(name));
+        for arg in rest {
+            cmd.arg(arg.as_slice());
+        }
+        let result: redis::Value = cmd.query_async(conn).await?;
+        Ok(result)
+    }
+
+    /// 将 RESP 数组帧或内联命令解码为参数列表
+    fn decode_raw_command(bytes: &[u8]) -> Result<Vec<Vec<u8>>> {
+        if bytes.first() != Some(&b'*') {
+            return Ok(bytes
+                .split(|b| b.is_ascii_whitespace())
+                .filter(|arg| !arg.is_empty())
+                .map(<[u8]>::to_vec)
+                .collect());
+        }
+
+        let frame = redis::parse_redis_value(bytes).map_err(|e| {
+            ConnectionError::Configuration(format!("Invalid RESP command frame: {e}"))
+        })?;
+        let redis::Value::Array(items) = frame else {
+            return Err(ConnectionError::Configuration(
+                "RESP command frame must be an array".to_string(),
+            ));
+        };
+
+        items
+            .into_iter()
+            .map(|item| match item {
+                redis::Value::BulkString(arg) => Ok(arg),
+                other => Err(ConnectionError::Configuration(format!(
+                    "RESP command arguments must be bulk strings, got {other:?}"
+                ))),
+            })
+            .collect()
+    }
+
+    // ==================== 测试辅助操作 (需要 testing 特性) ====================
+
+    /// 开启或关闭服务器的主动过期清理
+    ///
+    /// 关闭后过期键只会在被访问时删除，便于编写结果确定的过期测试。
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - 是否开启主动过期
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    #[cfg(feature = "testing")]
+    pub async fn debug_set_active_expire(
         conn: &mut ConnectionManager,
-        keys: &[K],
-    ) -> Result<Vec<Option<T>>>
+        enabled: bool,
+    ) -> Result<()> {
+        let _: () = redis::cmd("DEBUG")
+            .arg("SET-ACTIVE-EXPIRE")
+            .arg(if enabled { 1 } else { 0 })
+            .query_async(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// 让服务器阻塞指定的时间
+    ///
+    /// # Arguments
+    ///
+    /// * `seconds` - 阻塞时长（秒），可以是小数
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    #[cfg(feature = "testing")]
+    pub async fn debug_sleep(conn: &mut ConnectionManager, seconds: f64) -> Result<()> {
+        let _: () = redis::cmd("DEBUG")
+            .arg("SLEEP")
+            .arg(seconds)
+            .query_async(conn)
+            .await?;
+        Ok(())
+    }
+
+    // ==================== 调试操作 (需要 debug 特性) ====================
+
+    /// 执行任意 DEBUG 子命令
+    ///
+    /// 例如 `&["QUICKLIST-PACKED-THRESHOLD", "1kb"]`。DEBUG 命令可能阻塞甚至使服务器崩溃，
+    /// 仅应在调优实验或测试环境中使用。
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - DEBUG 之后的子命令及参数
+    ///
+    /// # Returns
+    ///
+    /// 返回原始回复
+    #[cfg(feature = "debug")]
+    pub async fn debug_command(
+        conn: &mut ConnectionManager,
+        args: &[&str],
+    ) -> Result<redis::Value> {
+        if args.is_empty() {
+            return Err(ConnectionError::Configuration(
+                "DEBUG requires a subcommand".to_string(),
+            ));
+        }
+
+        let result: redis::Value = redis::cmd("DEBUG").arg(args).query_async(conn).await?;
+        Ok(result)
+    }
+
+    /// 通过 MONITOR 实时获取服务器执行的所有命令
+    ///
+    /// **警告**：MONITOR 会让服务器把每条命令都复制给监视连接，吞吐量可能下降一半以上，
+    /// 绝不能在生产环境中使用，仅用于开发环境或排查集成测试中实际发出的命令序列。
+    ///
+    /// MONITOR 会独占连接，因此使用客户端单独建立一条连接，丢弃返回的流即关闭该连接。
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Redis 客户端
+    ///
+    /// # Returns
+    ///
+    /// 返回命令流，每一项是服务器格式化后的一行命令，
+    /// 例如 `1700000000.123456 [0 127.0.0.1:50000] "SET" "key" "value"`
+    #[cfg(feature = "debug")]
+    pub async fn monitor(client: &redis::Client) -> Result<impl Stream<Item = Result<String>>> {
+        use futures_util::StreamExt;
+
+        let monitor = client.get_async_monitor().await?;
+        Ok(monitor.into_on_message::<String>().map(Ok))
+    }
+
+    // ==================== 加密存储 (需要 encryption 特性) ====================
+
+    /// 以 JSON 序列化对象并用 AES-256-GCM 加密后存储
+    ///
+    /// 密文头部记录密钥版本号，参见 [`EncryptionKey`]。Redis 键名参与认证，
+    /// 密文被复制到其他键下后将无法解密。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 键名
+    /// * `value` - 要存储的对象
+    /// * `encryption_key` - 加密密钥
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    #[cfg(feature = "encryption")]
+    pub async fn set_encrypted<K, V>(
+        conn: &mut ConnectionManager,
+        key: K,
+        value: &V,
+        encryption_key: &EncryptionKey,
+    ) -> Result<()>
     where
-        K: ToRedisArgs + Clone + Send + Sync,
-        T: for<'de> Deserialize<'de>,
+        K: ToRedisArgs + Send + Sync,
+        V: Serialize,
     {
-        let json_strings: Vec<Option<String>> = Self::mget(conn, keys).await?;
-        let mut results = Vec::new();
+        let json =
+            serde_json::to_vec(value).map_err(|e| ConnectionError::Serialization(e.to_string()))?;
+        let aad = key.to_redis_args().concat();
+        let data = encryption_key.encrypt(&json, &aad)?;
+        Self::set(conn, key, data).await
+    }
 
-        for json_str in json_strings {
-            match json_str {
-                Some(s) => {
-                    let value = serde_json::from_str(&s)
-                        .map_err(|e| ConnectionError::Deserialization(e.to_string()))?;
-                    results.push(Some(value));
+    /// 读取并解密由 [`RedisUtils::set_encrypted`] 存储的对象
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 键名
+    /// * `encryption_keys` - 可用于解密的密钥列表，按密文头部的版本号选择
+    ///
+    /// # Returns
+    ///
+    /// 返回解密并反序列化的对象，键不存在时返回 None；
+    /// 没有对应版本的密钥、密钥错误或数据被篡改时返回反序列化错误
+    #[cfg(feature = "encryption")]
+    pub async fn get_encrypted<K, V>(
+        conn: &mut ConnectionManager,
+        key: K,
+        encryption_keys: &[EncryptionKey],
+    ) -> Result<Option<V>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: DeserializeOwned,
+    {
+        let aad = key.to_redis_args().concat();
+        let data: Option<Vec<u8>> = Self::get(conn, key).await?;
+        let Some(data) = data else {
+            return Ok(None);
+        };
+
+        let json = EncryptionKey::decrypt(encryption_keys, &data, &aad)?;
+        let value = serde_json::from_slice(&json)
+            .map_err(|e| ConnectionError::Deserialization(e.to_string()))?;
+        Ok(Some(value))
+    }
+
+    // ==================== 缓存操作 ====================
+
+    /// 负缓存哨兵值，以 NUL 字节开头，不可能与任何合法的 JSON 值冲突
+    const NEGATIVE_CACHE_SENTINEL: &'static str = "\0tin-redis-conn:negative";
+
+    /// 读取缓存的结构体对象，未命中时调用加载函数并写回缓存
+    ///
+    /// 启用负缓存时，加载函数返回 None 的结果也会以哨兵值缓存 `negative_ttl`，
+    /// 在此期间的重复查询直接返回 None 而不再调用加载函数，用于防止缓存穿透。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 键名
+    /// * `ttl` - 正常值的过期时间
+    /// * `negative_ttl` - 负缓存的过期时间，为 None 时不缓存未找到的结果
+    /// * `loader` - 缓存未命中时调用的加载函数
+    ///
+    /// # Returns
+    ///
+    /// 返回缓存或加载得到的对象，不存在时返回 None
+    pub async fn get_or_compute<K, T, F, Fut>(
+        conn: &mut ConnectionManager,
+        key: K,
+        ttl: Duration,
+        negative_ttl: Option<Duration>,
+        loader: F,
+    ) -> Result<Option<T>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        T: Serialize + for<'de> Deserialize<'de>,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Option<T>>>,
+    {
+        let cached: Option<String> = Self::get(conn, &key).await?;
+        if let Some(s) = cached {
+            if s == Self::NEGATIVE_CACHE_SENTINEL {
+                return Ok(None);
+            }
+            let value = serde_json::from_str(&s)
+                .map_err(|e| ConnectionError::Deserialization(e.to_string()))?;
+            return Ok(Some(value));
+        }
+
+        match loader().await? {
+            Some(value) => {
+                let json_str = serde_json::to_string(&value)
+                    .map_err(|e| ConnectionError::Serialization(e.to_string()))?;
+                Self::set_duration(conn, &key, json_str, ttl).await?;
+                Ok(Some(value))
+            }
+            None => {
+                if let Some(negative_ttl) = negative_ttl {
+                    Self::set_duration(conn, &key, Self::NEGATIVE_CACHE_SENTINEL, negative_ttl)
+                        .await?;
                 }
-                None => results.push(None),
+                Ok(None)
             }
         }
+    }
 
-        Ok(results)
+    /// 读取缓存，过期但仍可用的值会立即返回，并在后台刷新（stale-while-revalidate）
+    ///
+    /// 缓存值与其"软过期"时间戳一起保存，键本身按 `hard_ttl` 过期：
+    /// - 未命中（或已超过 `hard_ttl`）时同步调用加载函数并写回缓存；
+    /// - 命中且未超过 `soft_ttl` 时直接返回；
+    /// - 命中但已超过 `soft_ttl` 时立即返回旧值，同时在后台任务中调用加载函数刷新缓存。
+    ///   后台刷新通过 `{key}:swr-lock` 短锁保证同一个键同时只有一个刷新任务。
+    ///
+    /// 软过期时间基于本机时钟计算，多实例之间的时钟偏差会影响刷新时机。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 键名
+    /// * `soft_ttl` - 值保持新鲜的时长，超过后触发后台刷新
+    /// * `hard_ttl` - 值的最长保留时长，必须不小于 `soft_ttl`
+    /// * `loader` - 加载函数，未命中时同步调用，或在后台刷新时调用
+    ///
+    /// # Returns
+    ///
+    /// 返回缓存或加载得到的对象
+    pub async fn get_stale_while_revalidate<T, F, Fut>(
+        conn: &mut ConnectionManager,
+        key: &str,
+        soft_ttl: Duration,
+        hard_ttl: Duration,
+        loader: F,
+    ) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned + Send + 'static,
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+    {
+        /// 后台刷新锁的过期时间，防止刷新任务异常退出后锁无法释放
+        const REFRESH_LOCK_TTL: Duration = Duration::from_secs(30);
+
+        if soft_ttl > hard_ttl {
+            return Err(ConnectionError::Configuration(
+                "soft_ttl must not exceed hard_ttl".to_string(),
+            ));
+        }
+
+        let cached: Option<String> = Self::get(conn, key).await?;
+        let Some(cached) = cached else {
+            let value = loader().await?;
+            Self::set_duration(conn, key, Self::swr_entry(&value, soft_ttl)?, hard_ttl).await?;
+            return Ok(value);
+        };
+
+        let mut entry: serde_json::Value = serde_json::from_str(&cached)
+            .map_err(|e| ConnectionError::Deserialization(e.to_string()))?;
+        let stale_at = entry["stale_at"].as_u64().ok_or_else(|| {
+            ConnectionError::Deserialization(format!("Missing stale_at in cache entry {key}"))
+        })?;
+        let value: T = serde_json::from_value(entry["value"].take())
+            .map_err(|e| ConnectionError::Deserialization(e.to_string()))?;
+
+        if Self::unix_millis() < stale_at {
+            return Ok(value);
+        }
+
+        let lock_key = format!("{key}:swr-lock");
+        if Self::set_if_absent(conn, &lock_key, 1, Some(REFRESH_LOCK_TTL)).await? {
+            let mut conn = conn.clone();
+            let key = key.to_string();
+            tokio::spawn(async move {
+                let refreshed = async {
+                    let entry = Self::swr_entry(&loader().await?, soft_ttl)?;
+                    Self::set_duration(&mut conn, &key, entry, hard_ttl).await
+                }
+                .await;
+                if let Err(e) = refreshed {
+                    log::debug!("Background refresh of {key} failed: {e}");
+                }
+                if let Err(e) = Self::del(&mut conn, &lock_key).await {
+                    log::debug!("Failed to release refresh lock {lock_key}: {e}");
+                }
+            });
+        }
+
+        Ok(value)
+    }
+
+    /// 将缓存值与软过期时间戳一起编码
+    fn swr_entry<T: Serialize>(value: &T, soft_ttl: Duration) -> Result<String> {
+        let value = serde_json::to_value(value)
+            .map_err(|e| ConnectionError::Serialization(e.to_string()))?;
+        let entry = serde_json::json!({
+            "stale_at": Self::unix_millis() + soft_ttl.as_millis() as u64,
+            "value": value,
+        });
+        Ok(entry.to_string())
+    }
+
+    /// 获取当前的 Unix 时间戳（毫秒）
+    fn unix_millis() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis() as u64)
+    }
+
+    /// 将即将过期的热键降级到冷存储
+    ///
+    /// 热键剩余过期时间低于阈值时，通过 DUMP/RESTORE 把值按原类型复制到冷存储连接，
+    /// 并设置更长的过期时间；热键本身保持不变，按原计划过期。冷存储中已有同名键时会被覆盖。
+    /// DUMP 的序列化格式与 Redis 版本相关，冷存储的版本不能低于热存储。
+    ///
+    /// # Arguments
+    ///
+    /// * `hot_conn` - 热存储连接
+    /// * `cold_conn` - 冷存储连接
+    /// * `key` - 键名
+    /// * `threshold` - 剩余过期时间低于该值时降级
+    /// * `cold_ttl` - 冷存储中的过期时间，不能小于 1 毫秒
+    ///
+    /// # Returns
+    ///
+    /// 返回是否执行了降级，键不存在、没有过期时间或剩余时间充足时返回 false
+    pub async fn demote_if_expiring(
+        hot_conn: &mut ConnectionManager,
+        cold_conn: &mut ConnectionManager,
+        key: &str,
+        threshold: Duration,
+        cold_ttl: Duration,
+    ) -> Result<bool> {
+        let cold_millis = cold_ttl.as_millis() as u64;
+        if cold_millis == 0 {
+            return Err(ConnectionError::Configuration(
+                "TTL must be at least 1 millisecond".to_string(),
+            ));
+        }
+
+        let (pttl, dump): (i64, Option<Vec<u8>>) = redis::pipe()
+            .cmd("PTTL")
+            .arg(key)
+            .cmd("DUMP")
+            .arg(key)
+            .query_async(hot_conn)
+            .await?;
+
+        // -2 表示键不存在，-1 表示没有过期时间
+        if pttl < 0 || pttl as u128 >= threshold.as_millis() {
+            return Ok(false);
+        }
+        // 键可能恰好在 PTTL 与 DUMP 之间过期
+        let Some(dump) = dump else {
+            return Ok(false);
+        };
+
+        let _: () = redis::cmd("RESTORE")
+            .arg(key)
+            .arg(cold_millis)
+            .arg(dump)
+            .arg("REPLACE")
+            .query_async(cold_conn)
+            .await?;
+        Ok(true)
+    }
+
+    // ==================== 超时控制 ====================
+
+    /// 为单次操作设置超时
+    ///
+    /// 不同操作的延迟预算往往不同，例如缓存读取需要快速失败，而大范围查询可以等待更久。
+    /// 返回的守卫只对通过它发起的那一次操作生效。
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - 本次操作允许的最长耗时
+    ///
+    /// # Returns
+    ///
+    /// 返回超时守卫，例如 `RedisUtils::with_deadline(conn, timeout).get(key)`
+    pub fn with_deadline(conn: &mut ConnectionManager, timeout: Duration) -> Deadline<'_> {
+        Deadline::new(conn, timeout)
+    }
+
+    /// 为单次读取设置回复大小上限
+    ///
+    /// 用于防止 `lrange(key, 0, -1)` 之类的调用在超大集合上一次性拉取全部数据而耗尽内存。
+    ///
+    /// # Arguments
+    ///
+    /// * `max_elements` - 集合回复的最大元素数量，为 None 时不限制
+    /// * `max_bytes` - 回复的最大字节数，为 None 时不限制；范围读取不检查此项
+    ///
+    /// # Returns
+    ///
+    /// 返回大小守卫，例如 `RedisUtils::with_size_limit(conn, Some(10_000), None).lrange(key, 0, -1)`
+    pub fn with_size_limit(
+        conn: &mut ConnectionManager,
+        max_elements: Option<u64>,
+        max_bytes: Option<u64>,
+    ) -> SizeLimit<'_> {
+        SizeLimit::new(conn, max_elements, max_bytes)
+    }
+
+    // ==================== 阻塞列表操作 ====================
+
+    /// 阻塞式从列表左侧弹出元素（BLPOP），可安全取消
+    ///
+    /// 阻塞命令会占用整条连接，因此本方法不使用共享的 `ConnectionManager`，
+    /// 而是为每次调用建立一条专用连接。
+    ///
+    /// 关于超时与取消：
+    /// - `timeout` 是 Redis 服务端的阻塞时长，到期后返回 None；为零时无限期阻塞。
+    /// - 在等待期间丢弃返回的 future（例如请求被取消，或外层使用了 `tokio::time::timeout`），
+    ///   专用连接会随之关闭，服务端随即放弃该阻塞命令，不会等到 `timeout` 到期。
+    /// - 服务端只有在把元素写回给客户端时才会弹出它，连接关闭后不会再弹出任何元素；
+    ///   但若元素已弹出而回复尚在传输途中时被取消，该元素会丢失，需要严格不丢失时请使用
+    ///   BLMOVE 等可靠队列模式。
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Redis 客户端，用于建立专用连接
+    /// * `keys` - 按顺序检查的列表键名
+    /// * `timeout` - 服务端阻塞时长
+    ///
+    /// # Returns
+    ///
+    /// 返回 (列表键名, 元素)，超时返回 None
+    pub async fn blpop<K, V>(
+        client: &redis::Client,
+        keys: K,
+        timeout: Duration,
+    ) -> Result<Option<(String, V)>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        Self::blocking_pop(client, "BLPOP", keys, timeout).await
+    }
+
+    /// 阻塞式从列表右侧弹出元素（BRPOP），可安全取消
+    ///
+    /// 超时与取消的行为与 [`RedisUtils::blpop`] 相同。
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Redis 客户端，用于建立专用连接
+    /// * `keys` - 按顺序检查的列表键名
+    /// * `timeout` - 服务端阻塞时长
+    ///
+    /// # Returns
+    ///
+    /// 返回 (列表键名, 元素)，超时返回 None
+    pub async fn brpop<K, V>(
+        client: &redis::Client,
+        keys: K,
+        timeout: Duration,
+    ) -> Result<Option<(String, V)>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        Self::blocking_pop(client, "BRPOP", keys, timeout).await
+    }
+
+    /// 在专用连接上执行阻塞弹出命令，连接随 future 一起释放
+    async fn blocking_pop<K, V>(
+        client: &redis::Client,
+        command: &str,
+        keys: K,
+        timeout: Duration,
+    ) -> Result<Option<(String, V)>>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: FromRedisValue,
+    {
+        let mut conn = client.get_multiplexed_async_connection().await?;
+        let result: Option<(String, V)> = redis::cmd(command)
+            .arg(keys)
+            .arg(timeout.as_secs_f64())
+            .query_async(&mut conn)
+            .await?;
+        Ok(result)
+    }
+
+    /// 将列表队列转换为异步消息流，持续以 BLPOP 消费元素
+    ///
+    /// 流独占一条专用连接，丢弃流即关闭连接并停止消费。连接错误会按
+    /// [`PoolConfig::retry_delay`] 退避后重连，连续失败超过 `PoolConfig::max_retries`
+    /// 次时产出该错误并结束流；Redis 返回的其他错误（如 WRONGTYPE）同样会结束流。
+    /// 单个元素无法解析为 `V` 时产出反序列化错误，但流会继续消费后续元素。
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Redis 客户端，用于建立专用连接
+    /// * `key` - 列表键名
+    /// * `block_timeout` - 每次 BLPOP 的服务端阻塞时长，超时后会自动重新发起
+    ///
+    /// # Returns
+    ///
+    /// 返回逐个产出列表元素的异步流
+    pub fn list_consumer_stream<V>(
+        client: redis::Client,
+        key: String,
+        block_timeout: Duration,
+    ) -> impl Stream<Item = Result<V>>
+    where
+        V: FromRedisValue,
+    {
+        struct State {
+            client: redis::Client,
+            key: String,
+            conn: Option<redis::aio::MultiplexedConnection>,
+            failures: u32,
+            finished: bool,
+        }
+
+        let state = State {
+            client,
+            key,
+            conn: None,
+            failures: 0,
+            finished: false,
+        };
+        let retry = PoolConfig::default();
+
+        futures_util::stream::unfold(state, move |mut state| {
+            let retry = retry.clone();
+            async move {
+                if state.finished {
+                    return None;
+                }
+
+                loop {
+                    let popped: Result<Option<(String, redis::Value)>> = async {
+                        if state.conn.is_none() {
+                            state.conn =
+                                Some(state.client.get_multiplexed_async_connection().await?);
+                        }
+                        let conn = state.conn.as_mut().expect("connection initialized above");
+                        Ok(redis::cmd("BLPOP")
+                            .arg(&state.key)
+                            .arg(block_timeout.as_secs_f64())
+                            .query_async(conn)
+                            .await?)
+                    }
+                    .await;
+
+                    match popped {
+                        Ok(Some((_, value))) => {
+                            state.failures = 0;
+                            let item = V::from_redis_value(&value).map_err(|e| {
+                                ConnectionError::Deserialization(format!(
+                                    "Failed to decode list item: {e}"
+                                ))
+                            });
+                            return Some((item, state));
+                        }
+                        Ok(None) => state.failures = 0,
+                        Err(e) if e.is_connection_error() && state.failures < retry.max_retries => {
+                            let delay = retry.retry_delay(state.failures);
+                            log::debug!(
+                                "List consumer connection failed, retrying in {delay:?}: {e}"
+                            );
+                            state.conn = None;
+                            state.failures += 1;
+                            tokio::time::sleep(delay).await;
+                        }
+                        Err(e) => {
+                            state.finished = true;
+                            return Some((Err(e), state));
+                        }
+                    }
+                }
+            }
+        })
     }
 }
@@ -0,0 +1,197 @@
+use crate::error::{ConnectionError, Result};
+use redis::aio::ConnectionManager;
+
+/// 按权重随机抽样的成员池
+///
+/// 成员权重保存在哈希表 `{name}:weights` 中，累积权重保存在有序集合 `{name}:cumulative` 中：
+/// 每个成员的分数等于它及其之前所有成员的权重之和，抽样时在 `[0, 总权重)` 中取随机值，
+/// 选出第一个累积权重大于该值的成员。添加和移除成员时通过 Lua 脚本原子地重建累积权重，
+/// 因此这两个操作的开销与成员数量成正比，适合读多写少的场景。
+///
+/// 两个键使用相同的哈希标签，在集群中位于同一个槽。
+#[derive(Debug, Clone)]
+pub struct WeightedPool {
+    weights_key: String,
+    cumulative_key: String,
+}
+
+impl WeightedPool {
+    /// 创建加权成员池
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - 成员池名称，用于生成底层键名
+    ///
+    /// # Returns
+    ///
+    /// 返回 WeightedPool 实例
+    pub fn new(name: &str) -> Self {
+        Self {
+            weights_key: format!("{{{name}}}:weights"),
+            cumulative_key: format!("{{{name}}}:cumulative"),
+        }
+    }
+
+    /// 添加成员或更新成员的权重
+    ///
+    /// # Arguments
+    ///
+    /// * `member` - 成员
+    /// * `weight` - 权重，必须为正的有限数
+    ///
+    /// # Returns
+    ///
+    /// 返回更新后的成员数量
+    pub async fn add(
+        &self,
+        conn: &mut ConnectionManager,
+        member: &str,
+        weight: f64,
+    ) -> Result<u64> {
+        if !weight.is_finite() || weight <= 0.0 {
+            return Err(ConnectionError::Configuration(format!(
+                "Weight must be a positive finite number, got {weight}"
+            )));
+        }
+        self.update(conn, member, Some(weight)).await
+    }
+
+    /// 移除成员
+    ///
+    /// # Arguments
+    ///
+    /// * `member` - 成员
+    ///
+    /// # Returns
+    ///
+    /// 返回更新后的成员数量
+    pub async fn remove(&self, conn: &mut ConnectionManager, member: &str) -> Result<u64> {
+        self.update(conn, member, None).await
+    }
+
+    /// 修改权重表并重建累积权重
+    async fn update(
+        &self,
+        conn: &mut ConnectionManager,
+        member: &str,
+        weight: Option<f64>,
+    ) -> Result<u64> {
+        let script = redis::Script::new(
+            r"
+            if ARGV[2] then
+                redis.call('HSET', KEYS[1], ARGV[1], ARGV[2])
+            else
+                redis.call('HDEL', KEYS[1], ARGV[1])
+            end
+
+            local entries = redis.call('HGETALL', KEYS[1])
+            redis.call('DEL', KEYS[2])
+
+            local total = 0
+            local args = {}
+            for i = 1, #entries, 2 do
+                total = total + tonumber(entries[i + 1])
+                args[#args + 1] = string.format('%.17g', total)
+                args[#args + 1] = entries[i]
+                if #args >= 1000 then
+                    redis.call('ZADD', KEYS[2], unpack(args))
+                    args = {}
+                end
+            end
+            if #args > 0 then
+                redis.call('ZADD', KEYS[2], unpack(args))
+            end
+
+            return #entries / 2
+            ",
+        );
+
+        let mut invocation = script.prepare_invoke();
+        invocation
+            .key(&self.weights_key)
+            .key(&self.cumulative_key)
+            .arg(member);
+        if let Some(weight) = weight {
+            invocation.arg(weight);
+        }
+        let count: u64 = invocation.invoke_async(conn).await?;
+        Ok(count)
+    }
+
+    /// 按权重随机抽取一个成员
+    ///
+    /// # Returns
+    ///
+    /// 返回抽中的成员，成员池为空时返回 None
+    pub async fn sample(&self, conn: &mut ConnectionManager) -> Result<Option<String>> {
+        self.sample_with(conn, fastrand::f64()).await
+    }
+
+    /// 使用调用方提供的随机值按权重抽取成员
+    ///
+    /// # Arguments
+    ///
+    /// * `rng_value` - `[0, 1)` 范围内的随机值
+    ///
+    /// # Returns
+    ///
+    /// 返回抽中的成员，成员池为空时返回 None
+    pub async fn sample_with(
+        &self,
+        conn: &mut ConnectionManager,
+        rng_value: f64,
+    ) -> Result<Option<String>> {
+        if !(0.0..1.0).contains(&rng_value) {
+            return Err(ConnectionError::Configuration(format!(
+                "Random value must be in [0, 1), got {rng_value}"
+            )));
+        }
+
+        let script = redis::Script::new(
+            r"
+            local last = redis.call('ZRANGE', KEYS[1], -1, -1, 'WITHSCORES')
+            if #last == 0 then
+                return false
+            end
+
+            local target = tonumber(ARGV[1]) * tonumber(last[2])
+            local picked = redis.call('ZRANGEBYSCORE', KEYS[1],
+                '(' .. string.format('%.17g', target), '+inf', 'LIMIT', 0, 1)
+            return picked[1] or last[1]
+            ",
+        );
+        let member: Option<String> = script
+            .key(&self.cumulative_key)
+            .arg(rng_value)
+            .invoke_async(conn)
+            .await?;
+        Ok(member)
+    }
+
+    /// 获取所有成员及其权重
+    ///
+    /// # Returns
+    ///
+    /// 返回 (成员, 权重) 列表
+    pub async fn members(&self, conn: &mut ConnectionManager) -> Result<Vec<(String, f64)>> {
+        let result: Vec<(String, f64)> = redis::cmd("HGETALL")
+            .arg(&self.weights_key)
+            .query_async(conn)
+            .await?;
+        Ok(result)
+    }
+
+    /// 删除成员池的所有数据
+    ///
+    /// # Returns
+    ///
+    /// 返回操作结果
+    pub async fn clear(&self, conn: &mut ConnectionManager) -> Result<()> {
+        let _: () = redis::cmd("DEL")
+            .arg(&self.weights_key)
+            .arg(&self.cumulative_key)
+            .query_async(conn)
+            .await?;
+        Ok(())
+    }
+}